@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use palette::convert::FromColorUnclamped;
-use palette::{Lab, Lch, Xyz, Yxy};
+use palette::{Lab, Lch, Srgb, Xyz, Yxy};
 
 #[path = "../tests/convert/data_color_mine.rs"]
 #[allow(dead_code)]
@@ -15,6 +15,8 @@ use data_color_mine::{load_data, ColorMine};
     - yxy to xyz
     - lab to xyz
     - xyz to yxy
+    - rgb_u8 to lab
+    - rgb_u8 to lab (lookup table)
 */
 
 fn cie_conversion(c: &mut Criterion) {
@@ -28,6 +30,7 @@ fn cie_conversion(c: &mut Criterion) {
         .iter()
         .map(|x| Lch::from_color_unclamped(x.xyz))
         .collect();
+    let rgb_u8: Vec<Srgb<u8>> = colormine.iter().map(|x| x.rgb.into_format()).collect();
 
     group.bench_with_input("xyz to lab", &colormine, |b, colormine| {
         b.iter(|| {
@@ -78,6 +81,20 @@ fn cie_conversion(c: &mut Criterion) {
             }
         })
     });
+    group.bench_with_input("rgb_u8 to lab", &rgb_u8, |b, rgb_u8| {
+        b.iter(|| {
+            for c in rgb_u8 {
+                black_box(Lab::from_color_unclamped(c.into_format::<f32>()));
+            }
+        })
+    });
+    group.bench_with_input("rgb_u8 to lab (lookup table)", &rgb_u8, |b, rgb_u8| {
+        b.iter(|| {
+            for c in rgb_u8 {
+                black_box(Lab::from_srgb_u8(*c));
+            }
+        })
+    });
 
     group.finish();
 }
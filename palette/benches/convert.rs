@@ -0,0 +1,108 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use palette::{cast, FromColor, IntoColor, Lab, LinSrgb, Oklab, Srgb};
+
+#[path = "../tests/convert/data_color_mine.rs"]
+#[allow(dead_code)]
+mod data_color_mine;
+use data_color_mine::{load_data, ColorMine};
+
+/* Benches the following conversions:
+    - rgb to lab
+    - lab to rgb
+    - rgb to oklab
+    - oklab to rgb
+    - rgb_u8 to lab
+    - lab to rgb_u8
+    - rgb_u8 buffer to lab vec
+    - rgb_u8 buffer to oklab vec
+    - linsrgb slice to oklab in place
+*/
+
+fn convert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Hot conversions");
+    let colormine: Vec<ColorMine<f32>> = load_data();
+    let rgb: Vec<Srgb> = colormine.iter().map(|x| x.rgb).collect();
+    let rgb_u8: Vec<Srgb<u8>> = rgb.iter().map(|x| x.into_format()).collect();
+    let lab: Vec<Lab> = rgb.iter().map(|&x| x.into_color()).collect();
+    let oklab: Vec<Oklab> = rgb.iter().map(|&x| x.into_color()).collect();
+    let linear: Vec<LinSrgb> = colormine.iter().map(|x| x.linear_rgb).collect();
+    let buffer: Vec<u8> = cast::into_component_slice(&rgb_u8).to_vec();
+
+    group.bench_with_input("rgb to lab", &rgb, |b, rgb| {
+        b.iter(|| {
+            for &c in rgb {
+                black_box(Lab::from_color(c));
+            }
+        })
+    });
+    group.bench_with_input("lab to rgb", &lab, |b, lab| {
+        b.iter(|| {
+            for &c in lab {
+                black_box(Srgb::from_color(c));
+            }
+        })
+    });
+    group.bench_with_input("rgb to oklab", &rgb, |b, rgb| {
+        b.iter(|| {
+            for &c in rgb {
+                black_box(Oklab::from_color(c));
+            }
+        })
+    });
+    group.bench_with_input("oklab to rgb", &oklab, |b, oklab| {
+        b.iter(|| {
+            for &c in oklab {
+                black_box(Srgb::from_color(c));
+            }
+        })
+    });
+    group.bench_with_input("rgb_u8 to lab", &rgb_u8, |b, rgb_u8| {
+        b.iter(|| {
+            for &c in rgb_u8 {
+                black_box(Lab::from_color(c.into_format::<f32>()));
+            }
+        })
+    });
+    group.bench_with_input("lab to rgb_u8", &lab, |b, lab| {
+        b.iter(|| {
+            for &c in lab {
+                black_box(Srgb::from_color(c).into_format::<u8>());
+            }
+        })
+    });
+    group.bench_with_input("rgb_u8 buffer to lab vec", &buffer, |b, buffer| {
+        b.iter(|| {
+            let colors: Vec<Lab> = cast::from_component_slice::<Srgb<u8>>(buffer)
+                .iter()
+                .map(|c| c.into_format::<f32>().into_color())
+                .collect();
+            black_box(colors)
+        })
+    });
+    group.bench_with_input("rgb_u8 buffer to oklab vec", &buffer, |b, buffer| {
+        b.iter(|| {
+            let colors: Vec<Oklab> = cast::from_component_slice::<Srgb<u8>>(buffer)
+                .iter()
+                .map(|c| c.into_format::<f32>().into_color())
+                .collect();
+            black_box(colors)
+        })
+    });
+    group.bench_with_input("linsrgb slice to oklab in place", &linear, |b, linear| {
+        b.iter_batched_ref(
+            || cast::into_array_slice(linear).to_vec(),
+            |arrays| {
+                for array in arrays.iter_mut() {
+                    let oklab: Oklab = cast::from_array::<LinSrgb>(*array).into_color();
+                    *array = cast::into_array(oklab);
+                }
+            },
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, convert);
+criterion_main!(benches);
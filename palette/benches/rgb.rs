@@ -34,6 +34,7 @@ use data_color_mine::{load_data, ColorMine};
     - linear hwb to hwb
     - linsrgb to rgb
     - rgb_u8 to linsrgb_f32
+    - rgb_u8 to linsrgb_f32 (lookup table)
     - linsrgb_f32 to rgb_u8
 */
 
@@ -172,6 +173,17 @@ fn rgb_conversion(c: &mut Criterion) {
             }
         })
     });
+    group.bench_with_input(
+        "rgb_u8 to linsrgb_f32 (lookup table)",
+        &rgb_u8,
+        |b, rgb_u8| {
+            b.iter(|| {
+                for c in rgb_u8 {
+                    black_box(c.into_linear_f32());
+                }
+            })
+        },
+    );
     group.bench_with_input("linsrgb_f32 to rgb_u8", &colormine, |b, colormine| {
         b.iter(|| {
             for c in colormine {
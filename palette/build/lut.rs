@@ -0,0 +1,34 @@
+use std::fs::File;
+
+pub fn build() {
+    use std::path::Path;
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("lut.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create lut.rs");
+    build_srgb_decode(&mut writer);
+}
+
+fn build_srgb_decode(writer: &mut File) {
+    use std::io::Write;
+
+    let entries: Vec<String> = (0..=255u8)
+        .map(|value| {
+            let encoded = f64::from(value) / 255.0;
+            let linear = if encoded <= 0.04045 {
+                encoded / 12.92
+            } else {
+                ((encoded + 0.055) / 1.055).powf(2.4)
+            };
+
+            format!("{:?}", linear as f32)
+        })
+        .collect();
+
+    writeln!(
+        writer,
+        "/// Linear `f32` values for each 8 bit sRGB component value.\nstatic SRGB_U8_TO_LINEAR_F32: [f32; 256] = [{}];",
+        entries.join(", ")
+    )
+    .unwrap();
+}
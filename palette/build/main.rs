@@ -1,5 +1,7 @@
+mod lut;
 mod named;
 
 fn main() {
+    lut::build();
     named::build();
 }
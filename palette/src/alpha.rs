@@ -174,3 +174,47 @@ pub trait WithAlpha<A>: Sized {
         self.with_alpha(A::zero())
     }
 }
+
+/// Make every color in a `Vec` opaque, by adding an alpha channel.
+///
+/// This is useful when loading RGB data into a pipeline that works with
+/// RGBA. The alpha values are set to the maximum intensity, as with
+/// [`WithAlpha::opaque`].
+///
+/// ```
+/// use palette::{add_alpha_vec, Srgb, Srgba};
+///
+/// let colors = vec![Srgb::new(255u8, 0, 0), Srgb::new(0, 0, 255)];
+/// let with_alpha: Vec<Srgba<u8>> = add_alpha_vec(colors);
+///
+/// assert_eq!(with_alpha, [Srgba::new(255, 0, 0, 255), Srgba::new(0, 0, 255, 255)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn add_alpha_vec<C, A>(colors: Vec<C>) -> Vec<C::WithAlpha>
+where
+    C: WithAlpha<A>,
+    A: Stimulus,
+{
+    colors.into_iter().map(WithAlpha::opaque).collect()
+}
+
+/// Remove the alpha channel from every color in a `Vec`.
+///
+/// The alpha values are dropped without affecting the colors, as with
+/// [`WithAlpha::without_alpha`].
+///
+/// ```
+/// use palette::{strip_alpha_vec, Srgb, Srgba};
+///
+/// let colors = vec![Srgba::new(255u8, 0, 0, 128), Srgba::new(0, 0, 255, 0)];
+/// let without_alpha: Vec<Srgb<u8>> = strip_alpha_vec(colors);
+///
+/// assert_eq!(without_alpha, [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn strip_alpha_vec<C, A>(colors: Vec<C>) -> Vec<C::Color>
+where
+    C: WithAlpha<A>,
+{
+    colors.into_iter().map(WithAlpha::without_alpha).collect()
+}
@@ -51,6 +51,75 @@ impl<C, T: Stimulus> Alpha<C, T> {
     }
 }
 
+impl<C, T> Alpha<C, T> {
+    /// Transform the color with `f`, while keeping the alpha value as it is.
+    ///
+    /// ```
+    /// use palette::{LinSrgba, Srgba};
+    ///
+    /// let color = Srgba::new(0.5, 0.5, 0.5, 0.3);
+    /// let linear: LinSrgba = color.map_color(|color| color.into_linear());
+    ///
+    /// assert_eq!(linear.alpha, 0.3);
+    /// ```
+    #[inline]
+    pub fn map_color<D, F>(self, f: F) -> Alpha<D, T>
+    where
+        F: FnOnce(C) -> D,
+    {
+        Alpha {
+            color: f(self.color),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Transform the alpha value with `f`, while keeping the color as it is.
+    ///
+    /// ```
+    /// use palette::{Srgb, Srgba};
+    ///
+    /// let color = Srgba::new(0.5, 0.5, 0.5, 0.8);
+    /// let faded = color.map_alpha(|alpha| alpha / 2.0);
+    ///
+    /// assert_eq!(faded.color, Srgb::new(0.5, 0.5, 0.5));
+    /// assert_eq!(faded.alpha, 0.4);
+    /// ```
+    #[inline]
+    pub fn map_alpha<U, F>(self, f: F) -> Alpha<C, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Alpha {
+            color: self.color,
+            alpha: f(self.alpha),
+        }
+    }
+
+    /// Check if `self` and `other` have the same color, and alpha values
+    /// that differ by at most `epsilon`.
+    ///
+    /// The colors are compared exactly. This is useful when small
+    /// differences in transparency should be ignored, such as when removing
+    /// duplicates.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// let a = Srgba::new(0.2, 0.4, 0.6, 0.5);
+    /// let b = Srgba::new(0.2, 0.4, 0.6, 0.501);
+    ///
+    /// assert!(a.alpha_approx_eq(&b, 0.01));
+    /// assert!(!a.alpha_approx_eq(&b, 0.0001));
+    /// ```
+    pub fn alpha_approx_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool
+    where
+        C: PartialEq,
+        T: AbsDiffEq,
+    {
+        self.color == other.color && self.alpha.abs_diff_eq(&other.alpha, epsilon)
+    }
+}
+
 impl<C, T> PartialEq for Alpha<C, T>
 where
     T: PartialEq,
@@ -775,6 +844,86 @@ where
 mod test {
     use crate::encoding::Srgb;
     use crate::rgb::Rgba;
+    use crate::{FromColor, IntoColor, IsWithinBounds, Lcha, Oklab, Oklaba, Srgba};
+
+    #[test]
+    fn alpha_approx_eq() {
+        let a = Srgba::new(0.2f32, 0.4, 0.6, 128.0 / 255.0);
+        let b = Srgba::new(0.2f32, 0.4, 0.6, 129.0 / 255.0);
+        assert!(a.alpha_approx_eq(&b, 1.5 / 255.0));
+        assert!(!a.alpha_approx_eq(&b, 0.5 / 255.0));
+
+        let other_color = Srgba::new(0.2f32, 0.4, 0.7, 128.0 / 255.0);
+        assert!(!a.alpha_approx_eq(&other_color, 1.0));
+
+        let a = Srgba::new(10u8, 20, 30, 128);
+        let b = Srgba::new(10u8, 20, 30, 129);
+        assert!(a.alpha_approx_eq(&b, 1));
+        assert!(!a.alpha_approx_eq(&b, 0));
+    }
+
+    #[test]
+    fn map_color_keeps_alpha() {
+        let color = Rgba::<Srgb>::new(0.2, 0.4, 0.6, 0.5);
+        let mapped = color.map_color(|mut color| {
+            color.red = 1.0;
+            color
+        });
+
+        assert_relative_eq!(mapped, Rgba::new(1.0, 0.4, 0.6, 0.5));
+    }
+
+    #[test]
+    fn map_alpha_keeps_color() {
+        let color = Rgba::<Srgb>::new(0.2, 0.4, 0.6, 0.5);
+        let mapped = color.map_alpha(|alpha| 1.0 - alpha / 2.0);
+
+        assert_relative_eq!(mapped, Rgba::new(0.2, 0.4, 0.6, 0.75));
+    }
+
+    #[test]
+    fn is_within_bounds_checks_alpha() {
+        assert!(Rgba::<Srgb>::new(0.5, 0.5, 0.5, 1.0).is_within_bounds());
+        assert!(Rgba::<Srgb>::new(0.5, 0.5, 0.5, 0.0).is_within_bounds());
+        assert!(!Rgba::<Srgb>::new(0.5, 0.5, 0.5, 1.5).is_within_bounds());
+        assert!(!Rgba::<Srgb>::new(0.5, 0.5, 0.5, -0.5).is_within_bounds());
+        assert!(!Rgba::<Srgb>::new(1.5, 0.5, 0.5, 1.0).is_within_bounds());
+        assert!(Rgba::<Srgb, u8>::new(255, 0, 0, 255).is_within_bounds());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn add_and_strip_alpha_vec() {
+        use crate::{add_alpha_vec, strip_alpha_vec, Srgb as SrgbColor};
+
+        let colors = vec![
+            SrgbColor::new(0.2f32, 0.4, 0.6),
+            SrgbColor::new(1.0, 0.0, 0.5),
+        ];
+        let with_alpha: Vec<Srgba> = add_alpha_vec(colors.clone());
+
+        assert!(with_alpha.iter().all(|color| color.alpha == 1.0));
+        assert_eq!(strip_alpha_vec(with_alpha), colors);
+
+        let transparent = vec![Srgba::new(10u8, 20, 30, 0)];
+        let opaque: Vec<Srgba<u8>> = add_alpha_vec(strip_alpha_vec(transparent));
+        assert_eq!(opaque, [Srgba::new(10, 20, 30, 255)]);
+    }
+
+    #[test]
+    fn into_color_keeps_alpha() {
+        let srgba = Srgba::new(0.8, 0.3, 0.1, 0.25);
+
+        let lcha: Lcha = srgba.into_color();
+        assert_relative_eq!(lcha.alpha, 0.25);
+        assert_relative_eq!(Srgba::from_color(lcha), srgba, epsilon = 0.0001);
+
+        // Alpha is also added and removed when only one side has it.
+        let oklaba: Oklaba = Oklab::new(0.5, 0.1, 0.1).into_color();
+        assert_relative_eq!(oklaba.alpha, 1.0);
+        let oklab: Oklab = srgba.into_color();
+        assert_relative_eq!(oklab, Oklab::from_color(srgba.color), epsilon = 0.0001);
+    }
 
     #[test]
     fn lower_hex() {
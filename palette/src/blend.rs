@@ -41,7 +41,7 @@ use crate::ComponentWise;
 
 pub use self::blend::Blend;
 pub use self::equations::{Equation, Equations, Parameter, Parameters};
-pub use self::pre_alpha::PreAlpha;
+pub use self::pre_alpha::{premultiply_slice, unpremultiply_slice, PreAlpha};
 
 mod blend;
 mod equations;
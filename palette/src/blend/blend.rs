@@ -464,7 +464,7 @@ where
 
                     dst.alpha.clone()
                         * (two() * &a - &src.alpha)
-                        * (m3 * sixteen() - m2 * twelve() - m * three())
+                        * (m3 * sixteen() - m2 * twelve() + m * three())
                         + &a
                         - a * &dst.alpha
                         + b
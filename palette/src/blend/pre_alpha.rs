@@ -96,6 +96,72 @@ where
     }
 }
 
+/// Premultiply a buffer of colors in place.
+///
+/// The color components are multiplied by their alpha values, but stay in the
+/// same buffer. This is useful when preparing textures for the GPU, where
+/// premultiplied alpha is expected. The components are multiplied as they
+/// are, so non-linear colors, such as [`Srgba`](crate::Srgba), are
+/// premultiplied in their non-linear encoding. Colors with an alpha of `0.0`,
+/// or less, get all of their color components set to `0.0`.
+///
+/// ```
+/// use palette::{blend::premultiply_slice, Srgba};
+///
+/// let mut pixels = [Srgba::new(1.0, 0.5, 0.0, 0.5), Srgba::new(1.0, 1.0, 1.0, 0.0)];
+/// premultiply_slice(&mut pixels);
+///
+/// assert_eq!(pixels, [Srgba::new(0.5, 0.25, 0.0, 0.5), Srgba::new(0.0, 0.0, 0.0, 0.0)]);
+/// ```
+pub fn premultiply_slice<C, T, const N: usize>(colors: &mut [Alpha<C, T>])
+where
+    C: ArrayCast<Array = [T; N]>,
+    T: Zero + Arithmetics + PartialOrd + Clone,
+{
+    for color in colors {
+        let alpha = &color.alpha;
+        for component in crate::cast::into_array_mut(&mut color.color) {
+            *component = if *alpha > T::zero() {
+                component.clone() * alpha
+            } else {
+                T::zero()
+            };
+        }
+    }
+}
+
+/// Undo the premultiplication of a buffer of colors in place.
+///
+/// This is the opposite of [`premultiply_slice`], where the color components
+/// are divided by their alpha values. Colors with an alpha of `0.0` can't be
+/// restored, so all of their color components are set to `0.0`.
+///
+/// ```
+/// use palette::{blend::unpremultiply_slice, Srgba};
+///
+/// let mut pixels = [Srgba::new(0.5, 0.25, 0.0, 0.5), Srgba::new(0.2, 0.2, 0.2, 0.0)];
+/// unpremultiply_slice(&mut pixels);
+///
+/// assert_eq!(pixels, [Srgba::new(1.0, 0.5, 0.0, 0.5), Srgba::new(0.0, 0.0, 0.0, 0.0)]);
+/// ```
+pub fn unpremultiply_slice<C, T, const N: usize>(colors: &mut [Alpha<C, T>])
+where
+    C: ArrayCast<Array = [T; N]>,
+    T: Zero + Arithmetics + IsValidDivisor + PartialOrd + Clone,
+{
+    for color in colors {
+        let alpha = &color.alpha;
+        let is_valid = *alpha > T::zero() && alpha.is_valid_divisor();
+        for component in crate::cast::into_array_mut(&mut color.color) {
+            *component = if is_valid {
+                component.clone() / alpha
+            } else {
+                T::zero()
+            };
+        }
+    }
+}
+
 impl<C, T> Blend for PreAlpha<C, T>
 where
     C: Blend<Color = C> + ComponentWise<Scalar = T>,
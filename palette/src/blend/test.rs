@@ -354,6 +354,27 @@ fn soft_light() {
     assert_relative_eq!(LinSrgba::new(0.5, 0.0, 0.3, 1.0), a.soft_light(b));
 }
 
+#[test]
+fn soft_light_mid_grey() {
+    let grey = LinSrgb::new(0.5, 0.5, 0.5);
+
+    assert_relative_eq!(grey, grey.soft_light(grey));
+}
+
+#[test]
+fn soft_light_dark_backdrop() {
+    // The source is light and the backdrop is below 0.25, which uses the
+    // polynomial part of the W3C soft light function.
+    let a = LinSrgb::new(0.75, 0.75, 1.0);
+    let b = LinSrgb::new(0.2, 0.0, 0.25);
+
+    assert_relative_eq!(
+        LinSrgb::new(0.324, 0.0, 0.5),
+        a.soft_light(b),
+        epsilon = 0.000001
+    );
+}
+
 #[test]
 fn difference() {
     let a = LinSrgb::new(0.5, 0.0, 0.3);
@@ -389,3 +410,27 @@ fn exclusion() {
 
     assert_relative_eq!(LinSrgba::new(0.5, 0.0, 0.3, 1.0), a.difference(b));
 }
+
+#[test]
+fn premultiply_slice_round_trip() {
+    use crate::blend::{premultiply_slice, unpremultiply_slice};
+    use crate::Srgba;
+
+    let original = [
+        Srgba::new(0.2, 0.4, 0.6, 1.0),
+        Srgba::new(0.9, 0.1, 0.5, 0.25),
+        Srgba::new(0.3, 0.3, 0.7, 0.6),
+        Srgba::new(1.0, 0.8, 0.6, 0.0),
+    ];
+
+    let mut pixels = original;
+    premultiply_slice(&mut pixels);
+    assert_relative_eq!(pixels[1], Srgba::new(0.225, 0.025, 0.125, 0.25));
+    assert_eq!(pixels[3], Srgba::new(0.0, 0.0, 0.0, 0.0));
+
+    unpremultiply_slice(&mut pixels);
+    for (pixel, original) in pixels.iter().zip(&original).take(3) {
+        assert_relative_eq!(pixel, original, epsilon = 0.000001);
+    }
+    assert_eq!(pixels[3], Srgba::new(0.0, 0.0, 0.0, 0.0));
+}
@@ -15,7 +15,8 @@
 //! Arrays can be type checked to have the correct size at compile time, making
 //! casting free after optimization has removed the overhead from asserts. The
 //! same is true for arrays in slices and `Vec`s, because the length stays the
-//! same after casting.
+//! same after casting. Fixed size tiles, such as `[[u8; 3]; 4]`, can be cast
+//! with [`from_array_tile`] and [`into_array_tile`].
 //!
 //! ```
 //! use palette::{cast, Srgb, IntoColor};
@@ -64,7 +65,9 @@
 //! ```
 //!
 //! An alternative, for when the length can be trusted to be correct, is to use
-//! the `from_component_*` functions that panic on error.
+//! the `from_component_*` functions that panic on error. There's also the
+//! [`ColorSlice`] view, which wraps a read-only buffer for iterating over it
+//! as colors.
 //!
 //! This works:
 //!
@@ -104,6 +107,22 @@
 //! assert!(<&Srgb>::try_from(short_slice).is_err()); // Too few components.
 //! ```
 //!
+//! They can also be indexed like their arrays, which is convenient for
+//! generic numeric code. Indexing out of bounds panics, just like for arrays.
+//!
+//! ```
+//! use palette::{Srgb, Srgba};
+//!
+//! let mut color = Srgb::new(0.1, 0.5, 0.9);
+//! color[1] = 0.3;
+//!
+//! assert_eq!(color[0], 0.1);
+//! assert_eq!(color, Srgb::new(0.1, 0.3, 0.9));
+//!
+//! let transparent = Srgba::new(0.1, 0.5, 0.9, 0.4);
+//! assert_eq!(transparent[3], 0.4);
+//! ```
+//!
 //! ## Component Order
 //!
 //! The component order in an array or slice is not always the same as in the
@@ -148,7 +167,9 @@
 //! ```
 
 mod array;
+mod color_slice;
+mod low_bit_depth;
 mod packed;
 mod uint;
 
-pub use self::{array::*, packed::*, uint::*};
+pub use self::{array::*, color_slice::*, low_bit_depth::*, packed::*, uint::*};
@@ -208,6 +208,58 @@ where
     unsafe { transmute_copy(&ManuallyDrop::new(array)) }
 }
 
+/// Cast from a fixed size tile of colors to a tile of arrays.
+///
+/// ```
+/// use palette::{cast, Srgb};
+///
+/// let tile = [Srgb::new(23u8, 198, 76), Srgb::new(64, 139, 10)];
+/// assert_eq!(cast::into_array_tile(tile), [[23, 198, 76], [64, 139, 10]]);
+/// ```
+#[inline]
+pub fn into_array_tile<T, const N: usize>(colors: [T; N]) -> [T::Array; N]
+where
+    T: ArrayCast,
+{
+    assert_eq!(
+        core::mem::size_of::<[T::Array; N]>(),
+        core::mem::size_of::<[T; N]>()
+    );
+
+    // Safety: The requirements of implementing `ArrayCast`, as well as the size
+    // assert, ensures that transmuting `[T; N]` into `[T::Array; N]` is safe.
+    unsafe { transmute_copy(&ManuallyDrop::new(colors)) }
+}
+
+/// Cast from a fixed size tile of arrays to a tile of colors.
+///
+/// This is useful for processing a few pixels at a time, such as in SIMD
+/// friendly code, where the tile has a fixed length.
+///
+/// ```
+/// use palette::{cast, Srgb};
+///
+/// let tile = [[23, 198, 76], [64, 139, 10]];
+/// assert_eq!(
+///     cast::from_array_tile::<Srgb<u8>, 2>(tile),
+///     [Srgb::new(23, 198, 76), Srgb::new(64, 139, 10)]
+/// );
+/// ```
+#[inline]
+pub fn from_array_tile<T, const N: usize>(arrays: [T::Array; N]) -> [T; N]
+where
+    T: ArrayCast,
+{
+    assert_eq!(
+        core::mem::size_of::<[T::Array; N]>(),
+        core::mem::size_of::<[T; N]>()
+    );
+
+    // Safety: The requirements of implementing `ArrayCast`, as well as the size
+    // assert, ensures that transmuting `[T::Array; N]` into `[T; N]` is safe.
+    unsafe { transmute_copy(&ManuallyDrop::new(arrays)) }
+}
+
 /// Cast from a color type reference to an array reference.
 ///
 /// ```
@@ -1365,7 +1417,7 @@ pub enum VecCastErrorKind {
 
 #[cfg(test)]
 mod test {
-    use crate::{LinSrgb, Srgb};
+    use crate::{LinSrgb, LinSrgba, Srgb};
 
     #[cfg(feature = "std")]
     #[test]
@@ -1394,6 +1446,28 @@ mod test {
         assert_eq!(colors.capacity(), 8);
     }
 
+    #[test]
+    fn array_tile() {
+        let tile = [
+            [0.0f32, 0.25, 0.5, 1.0],
+            [1.0, 0.75, 0.5, 0.5],
+            [0.1, 0.2, 0.3, 0.0],
+            [0.4, 0.5, 0.6, 0.25],
+        ];
+
+        let colors = super::from_array_tile::<LinSrgba, 4>(tile);
+        assert_eq!(
+            colors,
+            [
+                LinSrgba::new(0.0, 0.25, 0.5, 1.0),
+                LinSrgba::new(1.0, 0.75, 0.5, 0.5),
+                LinSrgba::new(0.1, 0.2, 0.3, 0.0),
+                LinSrgba::new(0.4, 0.5, 0.6, 0.25),
+            ]
+        );
+        assert_eq!(super::into_array_tile(colors), tile);
+    }
+
     #[test]
     fn map_vec_in_place() {
         fn do_things(rgb: Srgb) -> LinSrgb {
@@ -0,0 +1,162 @@
+use core::{fmt, slice};
+
+use crate::ArrayExt;
+
+use super::{from_array_slice, try_from_component_slice, ArrayCast, SliceCastError};
+
+/// A read-only view of raw color data as colors, without copying it.
+///
+/// `ColorSlice` wraps a slice of arrays or components, such as the content of
+/// an image buffer, and makes it possible to iterate over and index it as
+/// colors. The data is cast once, when the view is created, using the same
+/// rules as [`from_array_slice`] and [`try_from_component_slice`].
+///
+/// ```
+/// use palette::{cast::ColorSlice, LinSrgb};
+///
+/// let buffer = [0.1f32, 0.2, 0.3, 0.4, 0.5, 0.6];
+/// let colors = ColorSlice::<LinSrgb>::try_from_components(&buffer).unwrap();
+///
+/// assert_eq!(colors.len(), 2);
+/// assert_eq!(colors.get(1), Some(&LinSrgb::new(0.4, 0.5, 0.6)));
+///
+/// let red_sum: f32 = colors.iter().map(|color| color.red).sum();
+/// assert_eq!(red_sum, 0.5);
+/// ```
+pub struct ColorSlice<'a, C> {
+    colors: &'a [C],
+}
+
+impl<'a, C> ColorSlice<'a, C>
+where
+    C: ArrayCast,
+{
+    /// Create a view of a slice of arrays, where each array is one color.
+    #[inline]
+    pub fn new(arrays: &'a [C::Array]) -> Self {
+        ColorSlice {
+            colors: from_array_slice(arrays),
+        }
+    }
+
+    /// Create a view of a slice of color components.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the length of `components` is not a multiple of
+    /// the color's array length.
+    #[inline]
+    pub fn try_from_components(
+        components: &'a [<C::Array as ArrayExt>::Item],
+    ) -> Result<Self, SliceCastError> {
+        try_from_component_slice(components).map(|colors| ColorSlice { colors })
+    }
+}
+
+impl<'a, C> ColorSlice<'a, C> {
+    /// Get the number of colors in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if the view has no colors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Get the color at `index`, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a C> {
+        self.colors.get(index)
+    }
+
+    /// Iterate over the colors in the view.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'a, C> {
+        self.colors.iter()
+    }
+
+    /// Get the colors as a slice, with the same lifetime as the data.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [C] {
+        self.colors
+    }
+}
+
+impl<'a, C> Clone for ColorSlice<'a, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C> Copy for ColorSlice<'a, C> {}
+
+impl<'a, C> fmt::Debug for ColorSlice<'a, C>
+where
+    C: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.colors).finish()
+    }
+}
+
+impl<'a, C> IntoIterator for ColorSlice<'a, C> {
+    type Item = &'a C;
+    type IntoIter = slice::Iter<'a, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &ColorSlice<'a, C> {
+    type Item = &'a C;
+    type IntoIter = slice::Iter<'a, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ColorSlice;
+    use crate::{cast::SliceCastError, LinSrgb, Srgba};
+
+    #[test]
+    fn iterate_raw_f32_buffer() {
+        let buffer = [0.0f32, 0.25, 0.5, 1.0, 0.75, 0.5, 0.1, 0.2, 0.3];
+        let colors = ColorSlice::<LinSrgb>::try_from_components(&buffer).unwrap();
+
+        assert_eq!(colors.len(), 3);
+        assert!(!colors.is_empty());
+        assert!(colors.iter().eq(&[
+            LinSrgb::new(0.0, 0.25, 0.5),
+            LinSrgb::new(1.0, 0.75, 0.5),
+            LinSrgb::new(0.1, 0.2, 0.3),
+        ]));
+        assert_eq!(colors.get(3), None);
+
+        // The colors point into the original buffer.
+        assert_eq!(colors.as_slice().as_ptr().cast::<f32>(), buffer.as_ptr());
+    }
+
+    #[test]
+    fn arrays_and_errors() {
+        let arrays = [[255u8, 0, 0, 128], [0, 255, 0, 255]];
+        let colors = ColorSlice::<Srgba<u8>>::new(&arrays);
+
+        let alphas = colors.into_iter().map(|color| color.alpha);
+        assert!(alphas.eq([128, 255].iter().copied()));
+
+        assert_eq!(
+            ColorSlice::<LinSrgb>::try_from_components(&[0.0, 1.0]).unwrap_err(),
+            SliceCastError
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    cast::UintCast,
+    rgb::{Rgb, Rgba},
+};
+
+/// An RGB color packed into a `u16`, with 5 bits for red, 6 bits for green and
+/// 5 bits for blue.
+///
+/// This format is common in embedded displays and LCD drivers, where it's
+/// also known as "high color". The red channel occupies the most significant
+/// bits.
+///
+/// Converting from `Rgb<S, u8>` rounds each component to the nearest value
+/// with the lower bit depth. Converting back expands the components by
+/// replicating their most significant bits into the missing low bits, so that
+/// the highest value (such as `0b11111`) becomes `255` and not `248`.
+///
+/// ```
+/// use palette::{cast::Rgb565, Srgb};
+///
+/// let packed = Rgb565::from(Srgb::new(255u8, 128, 0));
+/// assert_eq!(packed.color, 0b11111_100000_00000);
+///
+/// let unpacked: Srgb<u8> = Rgb565::from(0xFFFFu16).into();
+/// assert_eq!(unpacked, Srgb::new(255, 255, 255));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Rgb565 {
+    /// The color packed into a `u16` as `0bRRRRRGGGGGGBBBBB`.
+    pub color: u16,
+}
+
+impl Rgb565 {
+    /// Create a packed color from its 5, 6 and 5 bit components. Any excess
+    /// bits are ignored.
+    #[inline]
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Rgb565 {
+            color: ((red as u16 & 0x1F) << 11)
+                | ((green as u16 & 0x3F) << 5)
+                | (blue as u16 & 0x1F),
+        }
+    }
+
+    /// Get the 5, 6 and 5 bit red, green and blue components.
+    #[inline]
+    pub const fn into_components(self) -> (u8, u8, u8) {
+        (
+            (self.color >> 11) as u8 & 0x1F,
+            (self.color >> 5) as u8 & 0x3F,
+            self.color as u8 & 0x1F,
+        )
+    }
+}
+
+/// An RGBA color packed into a `u16`, with 4 bits for each of red, green, blue
+/// and alpha.
+///
+/// The red channel occupies the most significant bits and the alpha channel
+/// occupies the least significant bits. The components are converted to and
+/// from `Rgba<S, u8>` in the same way as for [`Rgb565`], so `0xF` becomes
+/// `0xFF`.
+///
+/// ```
+/// use palette::{cast::Rgba4444, Srgba};
+///
+/// let packed = Rgba4444::from(Srgba::new(255u8, 0, 136, 255));
+/// assert_eq!(packed.color, 0xF08F);
+///
+/// let unpacked: Srgba<u8> = packed.into();
+/// assert_eq!(unpacked, Srgba::new(255, 0, 136, 255));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Rgba4444 {
+    /// The color packed into a `u16` as `0xRGBA`.
+    pub color: u16,
+}
+
+impl Rgba4444 {
+    /// Create a packed color from its 4 bit components. Any excess bits are
+    /// ignored.
+    #[inline]
+    pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Rgba4444 {
+            color: ((red as u16 & 0xF) << 12)
+                | ((green as u16 & 0xF) << 8)
+                | ((blue as u16 & 0xF) << 4)
+                | (alpha as u16 & 0xF),
+        }
+    }
+
+    /// Get the 4 bit red, green, blue and alpha components.
+    #[inline]
+    pub const fn into_components(self) -> (u8, u8, u8, u8) {
+        (
+            (self.color >> 12) as u8 & 0xF,
+            (self.color >> 8) as u8 & 0xF,
+            (self.color >> 4) as u8 & 0xF,
+            self.color as u8 & 0xF,
+        )
+    }
+}
+
+/// Round an 8 bit value to the nearest value with `bits` bits.
+#[inline]
+fn reduce_bits(value: u8, bits: u32) -> u8 {
+    let max = (1u32 << bits) - 1;
+    ((u32::from(value) * max + 127) / 255) as u8
+}
+
+/// Expand a value with `bits` bits to 8 bits by repeating its bit pattern.
+#[inline]
+fn expand_bits(value: u8, bits: u32) -> u8 {
+    let mut expanded = u32::from(value) << (8 - bits);
+    let mut filled = bits;
+
+    while filled < 8 {
+        expanded |= expanded >> filled;
+        filled *= 2;
+    }
+
+    expanded as u8
+}
+
+impl<S> From<Rgb<S, u8>> for Rgb565 {
+    #[inline]
+    fn from(color: Rgb<S, u8>) -> Self {
+        Rgb565::new(
+            reduce_bits(color.red, 5),
+            reduce_bits(color.green, 6),
+            reduce_bits(color.blue, 5),
+        )
+    }
+}
+
+impl<S> From<Rgb565> for Rgb<S, u8> {
+    #[inline]
+    fn from(packed: Rgb565) -> Self {
+        let (red, green, blue) = packed.into_components();
+        Rgb::new(
+            expand_bits(red, 5),
+            expand_bits(green, 6),
+            expand_bits(blue, 5),
+        )
+    }
+}
+
+impl<S> From<Rgba<S, u8>> for Rgba4444 {
+    #[inline]
+    fn from(color: Rgba<S, u8>) -> Self {
+        Rgba4444::new(
+            reduce_bits(color.red, 4),
+            reduce_bits(color.green, 4),
+            reduce_bits(color.blue, 4),
+            reduce_bits(color.alpha, 4),
+        )
+    }
+}
+
+impl<S> From<Rgba4444> for Rgba<S, u8> {
+    #[inline]
+    fn from(packed: Rgba4444) -> Self {
+        let (red, green, blue, alpha) = packed.into_components();
+        Rgba::new(
+            expand_bits(red, 4),
+            expand_bits(green, 4),
+            expand_bits(blue, 4),
+            expand_bits(alpha, 4),
+        )
+    }
+}
+
+impl From<u16> for Rgb565 {
+    #[inline]
+    fn from(color: u16) -> Self {
+        Rgb565 { color }
+    }
+}
+
+impl From<Rgb565> for u16 {
+    #[inline]
+    fn from(packed: Rgb565) -> Self {
+        packed.color
+    }
+}
+
+impl From<u16> for Rgba4444 {
+    #[inline]
+    fn from(color: u16) -> Self {
+        Rgba4444 { color }
+    }
+}
+
+impl From<Rgba4444> for u16 {
+    #[inline]
+    fn from(packed: Rgba4444) -> Self {
+        packed.color
+    }
+}
+
+// Safety:
+//
+// `Rgb565` is a transparent wrapper around `u16`, which fulfills the
+// requirements of `UintCast`.
+unsafe impl UintCast for Rgb565 {
+    type Uint = u16;
+}
+
+// Safety:
+//
+// `Rgba4444` is a transparent wrapper around `u16`, which fulfills the
+// requirements of `UintCast`.
+unsafe impl UintCast for Rgba4444 {
+    type Uint = u16;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rgb565 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rgb565 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rgba4444 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rgba4444 {}
+
+#[cfg(test)]
+mod test {
+    use super::{Rgb565, Rgba4444};
+    use crate::{cast, Srgb, Srgba};
+
+    #[test]
+    fn rgb565_white_round_trip() {
+        let packed = Rgb565::from(Srgb::new(255u8, 255, 255));
+        assert_eq!(packed.color, 0xFFFF);
+
+        let unpacked: Srgb<u8> = packed.into();
+        assert_eq!(unpacked, Srgb::new(255, 255, 255));
+    }
+
+    #[test]
+    fn rgb565_bit_replication() {
+        let unpacked: Srgb<u8> = Rgb565::new(0b10000, 0b100000, 0b00001).into();
+        assert_eq!(unpacked, Srgb::new(0b10000100, 0b10000010, 0b00001000));
+    }
+
+    #[test]
+    fn rgb565_all_values_round_trip() {
+        for color in 0..=u16::MAX {
+            let unpacked: Srgb<u8> = Rgb565::from(color).into();
+            assert_eq!(Rgb565::from(unpacked).color, color);
+        }
+    }
+
+    #[test]
+    fn rgba4444_all_values_round_trip() {
+        for color in 0..=u16::MAX {
+            let unpacked: Srgba<u8> = Rgba4444::from(color).into();
+            assert_eq!(Rgba4444::from(unpacked).color, color);
+        }
+    }
+
+    #[test]
+    fn uint_slice_cast() {
+        let raw = &[0xF800u16, 0x07E0, 0x001F];
+        let colors = cast::from_uint_slice::<Rgb565>(raw);
+
+        assert_eq!(colors[0].into_components(), (31, 0, 0));
+        assert_eq!(colors[1].into_components(), (0, 63, 0));
+        assert_eq!(colors[2].into_components(), (0, 0, 31));
+    }
+}
@@ -219,7 +219,7 @@ where
 mod test {
     use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
     use crate::white_point::{WhitePoint, A, C, D50, D65};
-    use crate::Xyz;
+    use crate::{Srgb, Xyz};
 
     #[test]
     fn d65_to_d50_matrix_xyz_scaling() {
@@ -293,4 +293,17 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn srgb_white_to_d50() {
+        // Converting RGB to XYZ can only produce its own white point, so other
+        // white points have to go through adaptation.
+        let white: Xyz<D50, f64> = Srgb::new(1.0, 1.0, 1.0).adapt_into();
+        let d50 = D50::get_xyz().with_white_point();
+        assert_relative_eq!(white, d50, epsilon = 0.0001);
+
+        let white: Xyz<D65, f64> = Srgb::new(1.0, 1.0, 1.0).adapt_into();
+        let d65 = D65::get_xyz().with_white_point();
+        assert_relative_eq!(white, d65, epsilon = 0.0001);
+    }
 }
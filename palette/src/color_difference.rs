@@ -13,6 +13,46 @@ pub trait ColorDifference {
     /// Return the difference or distance between two colors.
     #[must_use]
     fn get_color_difference(self, other: Self) -> Self::Scalar;
+
+    /// Check if two colors are far enough apart to be told apart by eye.
+    ///
+    /// The colors are considered noticeably different when their color
+    /// difference is greater than 2.3, which is roughly the "just noticeable
+    /// difference" for CIEDE2000. See
+    /// [`is_noticeably_different_with_threshold`][ColorDifference::is_noticeably_different_with_threshold]
+    /// for using a different threshold.
+    ///
+    /// ```
+    /// use palette::{ColorDifference, Lab};
+    ///
+    /// let a: Lab = Lab::new(50.0f32, 10.0, 10.0);
+    /// let b = Lab::new(51.0f32, 10.0, 10.0);
+    /// let c = Lab::new(60.0f32, 10.0, 10.0);
+    ///
+    /// assert!(!a.is_noticeably_different(&b));
+    /// assert!(a.is_noticeably_different(&c));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn is_noticeably_different(&self, other: &Self) -> bool
+    where
+        Self: Clone,
+        Self::Scalar: Real + PartialOrd,
+    {
+        self.is_noticeably_different_with_threshold(other, Self::Scalar::from_f64(2.3))
+    }
+
+    /// Check if the color difference between two colors is greater than
+    /// `threshold`.
+    #[must_use]
+    #[inline]
+    fn is_noticeably_different_with_threshold(&self, other: &Self, threshold: Self::Scalar) -> bool
+    where
+        Self: Clone,
+        Self::Scalar: PartialOrd,
+    {
+        self.clone().get_color_difference(other.clone()) > threshold
+    }
 }
 
 /// Container of components necessary to calculate CIEDE color difference
@@ -163,3 +203,33 @@ where
         + (r_t * delta_c_prime * delta_big_h_prime) / (k_c * s_c * k_h * s_h))
         .sqrt()
 }
+
+#[cfg(test)]
+mod test {
+    use super::ColorDifference;
+    use crate::white_point::D65;
+    use crate::{Lab, Lch};
+
+    #[test]
+    fn noticeable_difference() {
+        let a = Lab::<D65, f64>::new(50.0, 0.0, 0.0);
+        let close = Lab::new(51.0, 0.0, 0.0);
+        let far = Lab::new(55.0, 0.0, 0.0);
+
+        // Sanity check that the distances are roughly what the test expects.
+        assert!((a.get_color_difference(close) - 1.0).abs() < 0.1);
+        assert!((a.get_color_difference(far) - 5.0).abs() < 0.5);
+
+        assert!(!a.is_noticeably_different(&close));
+        assert!(a.is_noticeably_different(&far));
+    }
+
+    #[test]
+    fn noticeable_difference_with_threshold() {
+        let a = Lch::<D65, f64>::new(50.0, 0.0, 0.0);
+        let b = Lch::new(51.0, 0.0, 0.0);
+
+        assert!(a.is_noticeably_different_with_threshold(&b, 0.5));
+        assert!(!a.is_noticeably_different_with_threshold(&b, 1.5));
+    }
+}
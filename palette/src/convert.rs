@@ -293,7 +293,7 @@ mod try_from_into_color;
 mod tests {
     use core::marker::PhantomData;
 
-    use super::{FromColor, FromColorUnclamped, IntoColor};
+    use super::{ConvertVia, FromColor, FromColorUnclamped, IntoColor};
     use crate::encoding::linear::Linear;
     use crate::luma::{Luma, LumaStandard};
     use crate::num::{One, Zero};
@@ -710,4 +710,59 @@ mod tests {
         let _hwb: Hwb<_, f64> = color.into_color();
         let _luma: Luma<Linear<crate::white_point::E>, f64> = color.into_color();
     }
+
+    #[test]
+    fn convert_via_matches_default() {
+        let color = crate::Srgb::new(0.2f64, 0.7, 0.4);
+        let expected: Lab<crate::white_point::D65, f64> = color.into_color();
+
+        let via_xyz: Lab<_, f64> = color.convert_via::<Xyz<_, f64>, _>();
+        assert_relative_eq!(via_xyz, expected, epsilon = 0.0000001);
+
+        let via_oklab: Lab<_, f64> = color.convert_via::<crate::Oklab<f64>, _>();
+        assert_relative_eq!(via_oklab, expected, epsilon = 0.001);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map_values() {
+        use std::collections::HashMap;
+
+        let background = crate::Srgb::new(0.1f32, 0.1, 0.2);
+        let accent = crate::Srgb::new(0.9f32, 0.4, 0.1);
+
+        let mut theme = HashMap::new();
+        theme.insert("background", background);
+        theme.insert("accent", accent);
+
+        let lab = HashMap::<_, Lab>::from_color_unclamped(theme.clone());
+        assert_eq!(lab.len(), 2);
+        assert_eq!(lab["background"], Lab::from_color_unclamped(background));
+        assert_eq!(lab["accent"], Lab::from_color_unclamped(accent));
+
+        let lab = HashMap::<_, Lab>::from_color(theme);
+        assert_eq!(lab["accent"], Lab::from_color(accent));
+    }
+
+    #[test]
+    fn tuples() {
+        let triad = (
+            crate::Srgb::new(0.8f64, 0.2, 0.1),
+            crate::Srgb::new(0.1, 0.8, 0.2),
+            crate::Srgb::new(0.2, 0.1, 0.8),
+        );
+
+        let (first, second, third): (Lab<_, f64>, Lab<_, f64>, Lab<_, f64>) = triad.into_color();
+        assert_eq!(first, Lab::from_color(triad.0));
+        assert_eq!(second, Lab::from_color(triad.1));
+        assert_eq!(third, Lab::from_color(triad.2));
+
+        let pair = <(Lch<_, f64>, Xyz<_, f64>)>::from_color_unclamped((triad.0, triad.1));
+        assert_eq!(pair.0, Lch::from_color_unclamped(triad.0));
+        assert_eq!(pair.1, Xyz::from_color_unclamped(triad.1));
+
+        let quad: (Hsl<_, f64>, Hsv<_, f64>, Hwb<_, f64>, Lab<_, f64>) =
+            (triad.0, triad.1, triad.2, triad.0).into_color();
+        assert_eq!(quad.3, first);
+    }
 }
@@ -1,5 +1,11 @@
 use crate::Clamp;
 
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
 #[cfg(feature = "std")]
 use crate::cast::{self, ArrayCast};
 
@@ -9,7 +15,8 @@ use super::FromColorUnclamped;
 ///
 /// `U: FromColor<T>` is implemented for every type `U: FromColorUnclamped<T> +
 /// Clamp`, as well as for `Vec<T>` and `Box<[T]>` where `T` and `U` have the
-/// same memory layout.
+/// same memory layout, for the values of a `HashMap`, and for tuples of two to
+/// four colors.
 ///
 /// See [`FromColorUnclamped`](crate::convert::FromColorUnclamped) for a
 /// lossless version of this trait. See
@@ -108,6 +115,58 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<K, T, U, S> FromColor<HashMap<K, T, S>> for HashMap<K, U, S>
+where
+    K: Eq + Hash,
+    U: FromColor<T>,
+    S: BuildHasher + Default,
+{
+    /// Convert all values, while keeping their keys.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use palette::{convert::FromColor, Lab, Srgb};
+    ///
+    /// let mut theme = HashMap::new();
+    /// theme.insert("accent", Srgb::new(0.8f32, 0.2, 0.1));
+    ///
+    /// let theme = HashMap::<_, Lab>::from_color(theme);
+    /// ```
+    #[inline]
+    fn from_color(color: HashMap<K, T, S>) -> Self {
+        color
+            .into_iter()
+            .map(|(key, value)| (key, U::from_color(value)))
+            .collect()
+    }
+}
+
+macro_rules! impl_from_color_tuple {
+    ($($from: ident => $to: ident: $color: ident),+) => {
+        impl<$($from, $to),+> FromColor<($($from,)+)> for ($($to,)+)
+        where
+            $($to: FromColor<$from>),+
+        {
+            /// Convert each color in the tuple.
+            #[inline]
+            fn from_color(($($color,)+): ($($from,)+)) -> Self {
+                ($($to::from_color($color),)+)
+            }
+        }
+    };
+}
+
+impl_from_color_tuple!(T1 => U1: color1, T2 => U2: color2);
+impl_from_color_tuple!(T1 => U1: color1, T2 => U2: color2, T3 => U3: color3);
+impl_from_color_tuple!(
+    T1 => U1: color1,
+    T2 => U2: color2,
+    T3 => U3: color3,
+    T4 => U4: color4
+);
+
 /// A trait for converting a color into another, in a possibly lossy way.
 ///
 /// `U: IntoColor<T>` is implemented for every type `T: FromColor<U>`.
@@ -135,3 +194,42 @@ where
         U::from_color(self)
     }
 }
+
+/// A trait for converting a color into another, through a chosen intermediate
+/// color space.
+///
+/// `T: ConvertVia` is implemented for every type, and the conversion is
+/// possible when `Mid: FromColorUnclamped<T>` and `Out: FromColor<Mid>`. The
+/// intermediate color is not clamped, so only the final result is limited to
+/// the bounds of `Out`. This makes it possible to compare the results of
+/// different conversion paths, such as going to [`Lab`](crate::Lab) through
+/// [`Xyz`](crate::Xyz) or through [`Oklab`](crate::Oklab).
+pub trait ConvertVia: Sized {
+    /// Convert into `Out`, by first converting into `Mid`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{convert::ConvertVia, IntoColor, Lab, Srgb, Xyz};
+    ///
+    /// let color = Srgb::new(0.8f32, 0.3, 0.3);
+    /// let lab: Lab = color.convert_via::<Xyz, Lab>();
+    ///
+    /// assert_relative_eq!(lab, color.into_color(), epsilon = 0.001);
+    /// ```
+    #[must_use]
+    fn convert_via<Mid, Out>(self) -> Out
+    where
+        Mid: FromColorUnclamped<Self>,
+        Out: FromColor<Mid>;
+}
+
+impl<T> ConvertVia for T {
+    #[inline]
+    fn convert_via<Mid, Out>(self) -> Out
+    where
+        Mid: FromColorUnclamped<Self>,
+        Out: FromColor<Mid>,
+    {
+        Out::from_color(Mid::from_color_unclamped(self))
+    }
+}
@@ -1,5 +1,11 @@
 pub use palette_derive::FromColorUnclamped;
 
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
 #[cfg(feature = "std")]
 use crate::cast::{self, ArrayCast};
 
@@ -73,6 +79,58 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<K, T, U, S> FromColorUnclamped<HashMap<K, T, S>> for HashMap<K, U, S>
+where
+    K: Eq + Hash,
+    U: FromColorUnclamped<T>,
+    S: BuildHasher + Default,
+{
+    /// Convert all values, while keeping their keys.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use palette::{convert::FromColorUnclamped, Lab, Srgb};
+    ///
+    /// let mut theme = HashMap::new();
+    /// theme.insert("accent", Srgb::new(0.8f32, 0.2, 0.1));
+    ///
+    /// let theme = HashMap::<_, Lab>::from_color_unclamped(theme);
+    /// ```
+    #[inline]
+    fn from_color_unclamped(color: HashMap<K, T, S>) -> Self {
+        color
+            .into_iter()
+            .map(|(key, value)| (key, U::from_color_unclamped(value)))
+            .collect()
+    }
+}
+
+macro_rules! impl_from_color_unclamped_tuple {
+    ($($from: ident => $to: ident: $color: ident),+) => {
+        impl<$($from, $to),+> FromColorUnclamped<($($from,)+)> for ($($to,)+)
+        where
+            $($to: FromColorUnclamped<$from>),+
+        {
+            /// Convert each color in the tuple.
+            #[inline]
+            fn from_color_unclamped(($($color,)+): ($($from,)+)) -> Self {
+                ($($to::from_color_unclamped($color),)+)
+            }
+        }
+    };
+}
+
+impl_from_color_unclamped_tuple!(T1 => U1: color1, T2 => U2: color2);
+impl_from_color_unclamped_tuple!(T1 => U1: color1, T2 => U2: color2, T3 => U3: color3);
+impl_from_color_unclamped_tuple!(
+    T1 => U1: color1,
+    T2 => U2: color2,
+    T3 => U3: color3,
+    T4 => U4: color4
+);
+
 /// A trait for unchecked conversion of a color into another.
 ///
 /// `U: IntoColorUnclamped<T>` is implemented for every type `T: FromColorUnclamped<U>`.
@@ -1,6 +1,10 @@
 use core::fmt;
 
-use crate::IsWithinBounds;
+use crate::{
+    cast::{self, ArrayCast},
+    num::IsFinite,
+    IsWithinBounds,
+};
 
 use super::FromColorUnclamped;
 
@@ -118,3 +122,130 @@ where
         U::try_from_color(self)
     }
 }
+
+/// The error type for a color conversion where the input or the output has
+/// infinite or NaN values.
+#[derive(Debug)]
+pub struct NotFinite<T> {
+    color: T,
+}
+
+impl<T> NotFinite<T> {
+    /// Create a new error wrapping a color
+    #[inline]
+    fn new(color: T) -> Self {
+        NotFinite { color }
+    }
+
+    /// Consume this error and return the wrapped color
+    #[inline]
+    pub fn color(self) -> T {
+        self.color
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for NotFinite<T> {
+    fn description(&self) -> &str {
+        "color conversion has infinite or NaN values"
+    }
+}
+
+impl<T> fmt::Display for NotFinite<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "color conversion has infinite or NaN values")
+    }
+}
+
+/// A trait for fallible conversion of one color from another, that fails for
+/// infinite and NaN values.
+///
+/// `U: TryFromColorFinite<T>` is implemented for every type
+/// `U: FromColorUnclamped<T>`, where both `T` and `U` can be cast to arrays of
+/// components. This is useful for processing untrusted color data, where
+/// NaN values would otherwise spread silently through the calculations.
+///
+/// The conversion is unclamped, so see [`TryFromColor`] for checking the
+/// bounds of the result.
+pub trait TryFromColorFinite<T>: Sized {
+    /// Convert from T, returning ok if all components of both T and the
+    /// result are finite, otherwise a `NotFinite` error is returned which
+    /// contains the converted color.
+    ///
+    ///```
+    /// use palette::convert::TryFromColorFinite;
+    /// use palette::{Hsl, Srgb};
+    ///
+    /// assert!(Srgb::try_from_color_finite(Hsl::new(150.0, 1.0, 0.5)).is_ok());
+    /// assert!(Srgb::try_from_color_finite(Hsl::new(150.0, f32::NAN, 0.5)).is_err());
+    /// ```
+    fn try_from_color_finite(t: T) -> Result<Self, NotFinite<Self>>;
+}
+
+impl<T, U, A, B, const N: usize, const M: usize> TryFromColorFinite<T> for U
+where
+    T: ArrayCast<Array = [A; N]>,
+    U: FromColorUnclamped<T> + ArrayCast<Array = [B; M]>,
+    A: IsFinite,
+    B: IsFinite,
+{
+    #[inline]
+    fn try_from_color_finite(t: T) -> Result<Self, NotFinite<Self>> {
+        let input_is_finite = cast::into_array_ref(&t).iter().all(A::is_finite);
+        let this = Self::from_color_unclamped(t);
+
+        if input_is_finite && cast::into_array_ref(&this).iter().all(B::is_finite) {
+            Ok(this)
+        } else {
+            Err(NotFinite::new(this))
+        }
+    }
+}
+
+/// A trait for fallible conversion of a color into another, that fails for
+/// infinite and NaN values.
+///
+/// `U: TryIntoColorFinite<T>` is implemented for every type
+/// `T: TryFromColorFinite<U>`.
+///
+/// See [`TryFromColorFinite`] for more details.
+pub trait TryIntoColorFinite<T>: Sized {
+    /// Convert into T, returning ok if all components of both self and the
+    /// result are finite, otherwise a `NotFinite` error is returned which
+    /// contains the converted color.
+    ///
+    ///```
+    /// use palette::convert::TryIntoColorFinite;
+    /// use palette::{Lch, Srgb};
+    ///
+    /// let result: Result<Srgb, _> = Lch::new(50.0, f32::INFINITY, 0.0).try_into_color_finite();
+    /// assert!(result.is_err());
+    /// ```
+    fn try_into_color_finite(self) -> Result<T, NotFinite<T>>;
+}
+
+impl<T, U> TryIntoColorFinite<U> for T
+where
+    U: TryFromColorFinite<T>,
+{
+    #[inline]
+    fn try_into_color_finite(self) -> Result<U, NotFinite<U>> {
+        U::try_from_color_finite(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TryFromColorFinite;
+    use crate::{Hsv, Lab, Srgb, Srgba};
+
+    #[test]
+    fn nan_input_is_an_error() {
+        assert!(Srgb::try_from_color_finite(Lab::new(50.0f32, 10.0, -20.0)).is_ok());
+
+        assert!(Srgb::try_from_color_finite(Hsv::new(f32::NAN, 0.0, 0.5)).is_err());
+
+        assert!(Srgb::try_from_color_finite(Lab::new(f32::NAN, 10.0, -20.0)).is_err());
+        assert!(Srgba::try_from_color_finite(Srgba::new(0.5f32, 0.5, 0.5, f32::NAN)).is_err());
+    }
+}
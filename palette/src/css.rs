@@ -0,0 +1,873 @@
+//! Parsing of CSS color functions.
+//!
+//! Colors that have a CSS function, such as `lab(50% 40 -20)` or
+//! `oklch(0.6 0.1 120deg)`, implement [`FromStr`](core::str::FromStr) for
+//! that function:
+//!
+//! ```
+//! use palette::{white_point::D50, Hsl, Lch, Oklab};
+//!
+//! let lch: Lch<D50> = "lch(50% 30 120)".parse().unwrap();
+//! let oklab: Oklab = "oklab(0.6 -0.1 0.1)".parse().unwrap();
+//! let hsl: Hsl = "hsl(120deg 50% 25%)".parse().unwrap();
+//!
+//! // Each type only accepts its own function.
+//! assert!("oklab(0.6 -0.1 0.1)".parse::<Lch<D50>>().is_err());
+//! ```
+//!
+//! The components can be numbers, percentages or the `none` keyword, which
+//! counts as `0`. Hues can have a `deg`, `rad`, `grad` or `turn` unit, and
+//! are in degrees if they don't have one. The components may be separated by
+//! commas instead of spaces, like in the legacy `hsl(120, 50%, 25%)` syntax,
+//! and `hsla()` is accepted as another name for `hsl()`. Alpha values are not
+//! supported here, since these types don't have an alpha component, but
+//! [`parse_color_mix`] accepts them.
+//!
+//! CSS defines `lab()` and `lch()` relative to the D50 white point, so they
+//! are only implemented for [`Lab<D50, T>`](Lab) and [`Lch<D50, T>`](Lch).
+//! Use [`chromatic_adaptation`](crate::chromatic_adaptation) to move the
+//! parsed color to another white point.
+//!
+//! Use [`detect_css_space`] to find out which color space a string is in,
+//! before picking a type to parse it as.
+//!
+//! The `color-mix()` function can be parsed with [`parse_color_mix`], which
+//! mixes the colors in the named color space and returns the result.
+
+use core::fmt;
+
+use crate::{
+    chromatic_adaptation::AdaptInto,
+    clamp,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding,
+    white_point::{D50, D65},
+    Alpha, GetHue, Hsl, Hwb, Lab, Lch, LinSrgb, Mix, Oklab, Oklch, Srgb, WithHue, Xyz,
+};
+
+/// The error type returned when parsing a CSS color function fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCssError {
+    /// The string didn't contain the expected function, such as `lab(...)`.
+    /// The expected function name is included.
+    UnexpectedFunction(&'static str),
+    /// The function didn't have the expected number of components, or it had
+    /// an alpha value where none was expected.
+    ComponentCount,
+    /// A component was not a valid number, percentage or angle.
+    InvalidComponent,
+    /// A `color-mix()` function didn't have a color space and two colors.
+    ArgumentCount,
+    /// A color in a `color-mix()` function was not a known color, or the
+    /// color space to mix in was not recognized.
+    UnknownColor,
+}
+
+impl fmt::Display for ParseCssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCssError::UnexpectedFunction(name) => {
+                write!(f, "expected a CSS `{}()` color", name)
+            }
+            ParseCssError::ComponentCount => f.write_str("wrong number of color components"),
+            ParseCssError::InvalidComponent => f.write_str("invalid color component"),
+            ParseCssError::ArgumentCount => f.write_str("wrong number of color-mix() arguments"),
+            ParseCssError::UnknownColor => f.write_str("unknown color or color space"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCssError {}
+
+/// The color spaces of the CSS color syntaxes. See [`detect_css_space`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// sRGB, from a hex code or an `rgb()` or `rgba()` function.
+    Srgb,
+    /// HSL, from an `hsl()` or `hsla()` function.
+    Hsl,
+    /// HWB, from an `hwb()` function.
+    Hwb,
+    /// CIE L\*a\*b\*, from a `lab()` function.
+    Lab,
+    /// CIE L\*C\*h°, from an `lch()` function.
+    Lch,
+    /// Oklab, from an `oklab()` function.
+    Oklab,
+    /// Oklch, from an `oklch()` function.
+    Oklch,
+}
+
+/// Find the color space of a CSS color, without parsing its components.
+///
+/// Only the function name, or the `#` of a hex code, is looked at, so the
+/// color may still be invalid. Returns `None` if the string doesn't start
+/// with a known color function or a `#`.
+///
+/// ```
+/// use palette::css::{detect_css_space, ColorSpace};
+///
+/// assert_eq!(detect_css_space("oklch(0.6 0.1 120)"), Some(ColorSpace::Oklch));
+/// assert_eq!(detect_css_space("#ff8000"), Some(ColorSpace::Srgb));
+/// assert_eq!(detect_css_space("color(display-p3 1 0 0)"), None);
+/// ```
+pub fn detect_css_space(input: &str) -> Option<ColorSpace> {
+    let input = input.trim_start();
+    if input.starts_with('#') {
+        return Some(ColorSpace::Srgb);
+    }
+
+    let name = input[..input.find('(')?].trim_end();
+
+    let spaces = [
+        ("rgb", ColorSpace::Srgb),
+        ("rgba", ColorSpace::Srgb),
+        ("hsl", ColorSpace::Hsl),
+        ("hsla", ColorSpace::Hsl),
+        ("hwb", ColorSpace::Hwb),
+        ("lab", ColorSpace::Lab),
+        ("lch", ColorSpace::Lch),
+        ("oklab", ColorSpace::Oklab),
+        ("oklch", ColorSpace::Oklch),
+    ];
+
+    spaces
+        .iter()
+        .find(|(function, _)| function.eq_ignore_ascii_case(name))
+        .map(|&(_, space)| space)
+}
+
+/// Parse a CSS `color-mix()` function and mix its colors.
+///
+/// The colors are mixed in the color space after `in`, which can be `srgb`,
+/// `hsl`, `hwb`, `lab`, `lch`, `oklab` or `oklch`. Hues are mixed along the
+/// shorter path around the color wheel. Each color can be a hex code, an
+/// `rgb()` or `rgba()` function, a named color if the `named_from_str`
+/// feature is enabled, or one of the color functions that are supported by
+/// this module. `lab()`, `lch()` and the `lab` and `lch` spaces use the D50
+/// white point, as in CSS, and are adapted to and from it.
+///
+/// The color functions may have an alpha value, such as in
+/// `oklch(0.6 0.2 0 / 0.5)`. The colors are then mixed with their alpha
+/// premultiplied, as in CSS, except for their hues, and the alpha values are
+/// mixed too.
+///
+/// Each color may have a percentage, which decides how much of it goes into
+/// the mix. A missing percentage becomes what remains up to `100%`, or `50%`
+/// if both are missing. Percentages that add up to less than `100%` make the
+/// result transparent, by setting its alpha to their sum, as in CSS.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{css::parse_color_mix, Alpha, IntoColor, Oklab, Oklch, Srgba};
+///
+/// let gray: Srgba = parse_color_mix("color-mix(in srgb, #ffffff, #000000)").unwrap();
+/// assert_relative_eq!(gray, Srgba::new(0.5, 0.5, 0.5, 1.0), epsilon = 0.0001);
+///
+/// let faded: Srgba = parse_color_mix("color-mix(in srgb, #ff0000 30%, #0000ff 30%)").unwrap();
+/// assert_relative_eq!(faded, Srgba::new(0.5, 0.0, 0.5, 0.6), epsilon = 0.0001);
+///
+/// let mixed: Alpha<Oklab, f32> =
+///     parse_color_mix("color-mix(in oklch, oklch(0.6 0.1 30deg) 25%, oklch(0.8 0.1 90deg))")
+///         .unwrap();
+/// let mixed: Oklch = mixed.color.into_color();
+/// assert_relative_eq!(mixed.l, 0.75, epsilon = 0.0001);
+/// assert_relative_eq!(mixed.hue.into_positive_degrees(), 75.0, epsilon = 0.01);
+///
+/// let translucent: Srgba =
+///     parse_color_mix("color-mix(in srgb, rgb(255 0 0 / 0.5), #0000ff)").unwrap();
+/// assert_relative_eq!(translucent, Srgba::new(1.0 / 3.0, 0.0, 2.0 / 3.0, 0.75), epsilon = 0.0001);
+/// ```
+pub fn parse_color_mix<C>(input: &str) -> Result<Alpha<C, f32>, ParseCssError>
+where
+    C: FromColorUnclamped<Xyz<D65, f32>>,
+{
+    let input = input.trim();
+    let name = "color-mix";
+    let arguments = input
+        .get(..name.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+        .and_then(|_| input[name.len()..].trim_start().strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or(ParseCssError::UnexpectedFunction(name))?;
+
+    let [space, first, second] = split_arguments(arguments)?;
+
+    let space = space
+        .strip_prefix("in")
+        .filter(|space| space.starts_with(char::is_whitespace))
+        .and_then(|space| parse_mix_space(space.trim()))
+        .ok_or(ParseCssError::UnknownColor)?;
+
+    let first = parse_mix_color(first)?;
+    let second = parse_mix_color(second)?;
+
+    let (first_percentage, second_percentage) = match (first.percentage, second.percentage) {
+        (None, None) => (50.0, 50.0),
+        (Some(first), None) => (first, 100.0 - first),
+        (None, Some(second)) => (100.0 - second, second),
+        (Some(first), Some(second)) => (first, second),
+    };
+
+    let sum = first_percentage + second_percentage;
+    if sum <= 0.0 {
+        return Err(ParseCssError::InvalidComponent);
+    }
+
+    let factor = second_percentage / sum;
+    let alpha = first.alpha * (1.0 - factor) + second.alpha * factor;
+
+    // Mixing premultiplied colors is the same as weighing each color by its
+    // alpha, which only applies to the components that aren't hues.
+    let premultiplied_factor = if alpha > 0.0 {
+        second.alpha * factor / alpha
+    } else {
+        factor
+    };
+
+    let mixed = mix_in(
+        space,
+        first.color,
+        second.color,
+        factor as f32,
+        premultiplied_factor as f32,
+    );
+    let alpha = if sum < 100.0 {
+        alpha * sum / 100.0
+    } else {
+        alpha
+    };
+
+    Ok(Alpha {
+        color: C::from_color_unclamped(mixed),
+        alpha: alpha as f32,
+    })
+}
+
+/// Split the arguments of `color-mix()` at the commas that are not inside
+/// another function.
+fn split_arguments<'a>(arguments: &'a str) -> Result<[&'a str; 3], ParseCssError> {
+    let mut result = [""; 3];
+    let mut count = 0;
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    let mut push = |argument: &'a str| {
+        let slot = result.get_mut(count).ok_or(ParseCssError::ArgumentCount)?;
+        *slot = argument.trim();
+        count += 1;
+        Ok(())
+    };
+
+    for (index, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(ParseCssError::InvalidComponent)?
+            }
+            ',' if depth == 0 => {
+                push(&arguments[start..index])?;
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    push(&arguments[start..])?;
+
+    if count != 3 {
+        return Err(ParseCssError::ArgumentCount);
+    }
+
+    Ok(result)
+}
+
+fn parse_mix_space(name: &str) -> Option<ColorSpace> {
+    let spaces = [
+        ("srgb", ColorSpace::Srgb),
+        ("hsl", ColorSpace::Hsl),
+        ("hwb", ColorSpace::Hwb),
+        ("lab", ColorSpace::Lab),
+        ("lch", ColorSpace::Lch),
+        ("oklab", ColorSpace::Oklab),
+        ("oklch", ColorSpace::Oklch),
+    ];
+
+    spaces
+        .iter()
+        .find(|(space, _)| space.eq_ignore_ascii_case(name))
+        .map(|&(_, space)| space)
+}
+
+/// A color in `color-mix()`.
+struct MixColor {
+    color: Xyz<D65, f32>,
+    alpha: f64,
+    percentage: Option<f64>,
+}
+
+/// Parse a color and its optional percentage, which can be before or after
+/// the color.
+fn parse_mix_color(input: &str) -> Result<MixColor, ParseCssError> {
+    let parse_percentage = |percentage: &str| {
+        if !percentage.ends_with('%') {
+            return Err(ParseCssError::InvalidComponent);
+        }
+
+        let percentage = parse_number(percentage, 100.0)?;
+        if (0.0..=100.0).contains(&percentage) {
+            Ok(percentage)
+        } else {
+            Err(ParseCssError::InvalidComponent)
+        }
+    };
+
+    let mut trailing = input.rsplitn(2, char::is_whitespace);
+    let mut leading = input.splitn(2, char::is_whitespace);
+    let (color, percentage) = match (trailing.next(), trailing.next()) {
+        (Some(percentage), Some(color)) if percentage.ends_with('%') => {
+            (color, Some(parse_percentage(percentage)?))
+        }
+        _ => match (leading.next(), leading.next()) {
+            (Some(percentage), Some(color))
+                if percentage.ends_with('%') && !percentage.contains('(') =>
+            {
+                (color, Some(parse_percentage(percentage)?))
+            }
+            _ => (input, None),
+        },
+    };
+
+    let color = color.trim();
+    let (color, alpha) = match detect_css_space(color) {
+        Some(ColorSpace::Srgb) if color.starts_with('#') => {
+            let color: Srgb<u8> = color.parse().map_err(|_| ParseCssError::UnknownColor)?;
+            (color.into_format::<f32>().into_color_unclamped(), 1.0)
+        }
+        Some(ColorSpace::Srgb) => {
+            parse_mix_function(color, |color: Srgb<f32>| color.into_color_unclamped())?
+        }
+        Some(ColorSpace::Hsl) => parse_mix_function(color, |color: Hsl<encoding::Srgb, f32>| {
+            color.into_color_unclamped()
+        })?,
+        Some(ColorSpace::Hwb) => parse_mix_function(color, |color: Hwb<encoding::Srgb, f32>| {
+            color.into_color_unclamped()
+        })?,
+        Some(ColorSpace::Lab) => {
+            parse_mix_function(color, |color: Lab<D50, f32>| color.adapt_into())?
+        }
+        Some(ColorSpace::Lch) => {
+            parse_mix_function(color, |color: Lch<D50, f32>| color.adapt_into())?
+        }
+        Some(ColorSpace::Oklab) => {
+            parse_mix_function(color, |color: Oklab<f32>| color.into_color_unclamped())?
+        }
+        Some(ColorSpace::Oklch) => {
+            parse_mix_function(color, |color: Oklch<f32>| color.into_color_unclamped())?
+        }
+        None => (parse_named(color)?, 1.0),
+    };
+
+    Ok(MixColor {
+        color,
+        alpha,
+        percentage,
+    })
+}
+
+/// Parse a color function with an optional alpha value, and convert the color
+/// with `into_xyz`.
+fn parse_mix_function<C>(
+    input: &str,
+    into_xyz: impl FnOnce(C) -> Xyz<D65, f32>,
+) -> Result<(Xyz<D65, f32>, f64), ParseCssError>
+where
+    C: CssFunction,
+{
+    let (color, alpha) = parse_function_with_alpha(input).map_err(|error| match error {
+        ParseCssError::UnexpectedFunction(_) => ParseCssError::UnknownColor,
+        error => error,
+    })?;
+
+    Ok((into_xyz(color), alpha))
+}
+
+impl CssFunction for Srgb<f32> {
+    const NAMES: &'static [&'static str] = &["rgb", "rgba"];
+
+    /// The components are numbers between `0` and `255`, or percentages.
+    fn from_components([red, green, blue]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Srgb::new(
+            (parse_number(red, 255.0)? / 255.0) as f32,
+            (parse_number(green, 255.0)? / 255.0) as f32,
+            (parse_number(blue, 255.0)? / 255.0) as f32,
+        ))
+    }
+}
+
+#[cfg(feature = "named_from_str")]
+fn parse_named(name: &str) -> Result<Xyz<D65, f32>, ParseCssError> {
+    let color =
+        crate::named::from_str(&name.to_ascii_lowercase()).ok_or(ParseCssError::UnknownColor)?;
+    Ok(color.into_format::<f32>().into_color_unclamped())
+}
+
+#[cfg(not(feature = "named_from_str"))]
+fn parse_named(_name: &str) -> Result<Xyz<D65, f32>, ParseCssError> {
+    Err(ParseCssError::UnknownColor)
+}
+
+/// Mix two colors in `space`, where `factor` is how much of `second` to use
+/// for hues, and `premultiplied_factor` is how much to use for the other
+/// components.
+fn mix_in(
+    space: ColorSpace,
+    first: Xyz<D65, f32>,
+    second: Xyz<D65, f32>,
+    factor: f32,
+    premultiplied_factor: f32,
+) -> Xyz<D65, f32> {
+    fn mix<C, Wp>(first: Xyz<Wp, f32>, second: Xyz<Wp, f32>, factor: f32) -> Xyz<Wp, f32>
+    where
+        C: FromColorUnclamped<Xyz<Wp, f32>> + IntoColorUnclamped<Xyz<Wp, f32>> + Mix<Scalar = f32>,
+    {
+        C::from_color_unclamped(first)
+            .mix(C::from_color_unclamped(second), factor)
+            .into_color_unclamped()
+    }
+
+    fn mix_polar<C, Wp>(
+        first: Xyz<Wp, f32>,
+        second: Xyz<Wp, f32>,
+        factor: f32,
+        premultiplied_factor: f32,
+    ) -> Xyz<Wp, f32>
+    where
+        C: FromColorUnclamped<Xyz<Wp, f32>>
+            + IntoColorUnclamped<Xyz<Wp, f32>>
+            + Mix<Scalar = f32>
+            + GetHue
+            + WithHue<<C as GetHue>::Hue>
+            + Clone,
+    {
+        let first = C::from_color_unclamped(first);
+        let second = C::from_color_unclamped(second);
+        let hue = first.clone().mix(second.clone(), factor).get_hue();
+        let mixed = first.mix(second, premultiplied_factor);
+
+        match hue {
+            Some(hue) => mixed.with_hue(hue),
+            None => mixed,
+        }
+        .into_color_unclamped()
+    }
+
+    match space {
+        ColorSpace::Srgb => {
+            // sRGB is mixed without linearizing it first, as in CSS, by
+            // temporarily treating the components as linear.
+            let first = Srgb::from_color_unclamped(first);
+            let second = Srgb::from_color_unclamped(second);
+            let mixed = LinSrgb::new(first.red, first.green, first.blue).mix(
+                LinSrgb::new(second.red, second.green, second.blue),
+                premultiplied_factor,
+            );
+            Srgb::new(mixed.red, mixed.green, mixed.blue).into_color_unclamped()
+        }
+        ColorSpace::Hsl => {
+            mix_polar::<Hsl<encoding::Srgb, f32>, _>(first, second, factor, premultiplied_factor)
+        }
+        ColorSpace::Hwb => {
+            mix_polar::<Hwb<encoding::Srgb, f32>, _>(first, second, factor, premultiplied_factor)
+        }
+        // CSS uses the D50 white point for the `lab` and `lch` spaces.
+        ColorSpace::Lab => {
+            let first: Xyz<D50, f32> = first.adapt_into();
+            let second: Xyz<D50, f32> = second.adapt_into();
+            mix::<Lab<D50, f32>, _>(first, second, premultiplied_factor).adapt_into()
+        }
+        ColorSpace::Lch => {
+            let first: Xyz<D50, f32> = first.adapt_into();
+            let second: Xyz<D50, f32> = second.adapt_into();
+            mix_polar::<Lch<D50, f32>, _>(first, second, factor, premultiplied_factor).adapt_into()
+        }
+        ColorSpace::Oklab => mix::<Oklab<f32>, _>(first, second, premultiplied_factor),
+        ColorSpace::Oklch => {
+            mix_polar::<Oklch<f32>, _>(first, second, factor, premultiplied_factor)
+        }
+    }
+}
+
+/// A color with a CSS function, which can be created from the three
+/// components of that function.
+pub(crate) trait CssFunction: Sized {
+    /// The names of the function, where the first one is its main name.
+    const NAMES: &'static [&'static str];
+
+    /// Create the color from the components of its function.
+    fn from_components(components: [&str; 3]) -> Result<Self, ParseCssError>;
+}
+
+/// Parse the CSS function of `C`, which must not have an alpha value.
+pub(crate) fn parse_function<C: CssFunction>(input: &str) -> Result<C, ParseCssError> {
+    match split_function(input, C::NAMES)? {
+        (components, None) => C::from_components(components),
+        (_, Some(_)) => Err(ParseCssError::ComponentCount),
+    }
+}
+
+/// Parse the CSS function of `C` and its alpha value, which is `1` if it's
+/// missing.
+fn parse_function_with_alpha<C: CssFunction>(input: &str) -> Result<(C, f64), ParseCssError> {
+    let (components, alpha) = split_function(input, C::NAMES)?;
+    let alpha = match alpha {
+        Some(alpha) => clamp(parse_number(alpha, 1.0)?, 0.0, 1.0),
+        None => 1.0,
+    };
+
+    Ok((C::from_components(components)?, alpha))
+}
+
+/// Split `input` into the three components of the CSS function with one of
+/// the names in `names`, and its alpha value. The alpha value can come after
+/// a `/`, or be a fourth component when the components are separated by
+/// commas.
+fn split_function<'a>(
+    input: &'a str,
+    names: &[&'static str],
+) -> Result<([&'a str; 3], Option<&'a str>), ParseCssError> {
+    let input = input.trim();
+    let arguments = names
+        .iter()
+        .find_map(|name| {
+            input
+                .get(..name.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+                .and_then(|_| input[name.len()..].trim_start().strip_prefix('('))
+                .and_then(|rest| rest.strip_suffix(')'))
+        })
+        .ok_or(ParseCssError::UnexpectedFunction(names[0]))?;
+
+    let (arguments, alpha) = match arguments.find('/') {
+        Some(index) => (&arguments[..index], Some(arguments[index + 1..].trim())),
+        None => (arguments, None),
+    };
+    let legacy = arguments.contains(',');
+
+    let mut components = arguments
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|component| !component.is_empty());
+
+    let mut result = [""; 3];
+    for slot in &mut result {
+        *slot = components.next().ok_or(ParseCssError::ComponentCount)?;
+    }
+
+    let alpha = match (alpha, components.next()) {
+        (Some(alpha), None) if !alpha.is_empty() && !alpha.contains(char::is_whitespace) => {
+            Some(alpha)
+        }
+        (None, Some(alpha)) if legacy => Some(alpha),
+        (None, None) => None,
+        _ => return Err(ParseCssError::ComponentCount),
+    };
+
+    if components.next().is_some() {
+        return Err(ParseCssError::ComponentCount);
+    }
+
+    Ok((result, alpha))
+}
+
+/// Parse a number or a percentage, where `100%` becomes `percent_reference`.
+pub(crate) fn parse_number(component: &str, percent_reference: f64) -> Result<f64, ParseCssError> {
+    if component.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+
+    let (number, scale) = match component.strip_suffix('%') {
+        Some(number) => (number, percent_reference / 100.0),
+        None => (component, 1.0),
+    };
+
+    parse_finite(number).map(|number| number * scale)
+}
+
+/// Parse a hue angle, and return it in degrees.
+pub(crate) fn parse_hue(component: &str) -> Result<f64, ParseCssError> {
+    if component.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+
+    // `grad` has to go before `rad`, since it ends with `rad`.
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f64::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    for &(unit, degrees) in &units {
+        let split = component.len().checked_sub(unit.len());
+        if let Some(split) = split.filter(|&split| component.is_char_boundary(split)) {
+            let (number, suffix) = component.split_at(split);
+            if suffix.eq_ignore_ascii_case(unit) {
+                return parse_finite(number).map(|number| number * degrees);
+            }
+        }
+    }
+
+    parse_finite(component)
+}
+
+fn parse_finite(number: &str) -> Result<f64, ParseCssError> {
+    number
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or(ParseCssError::InvalidComponent)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        detect_css_space, parse_color_mix, parse_function, parse_hue, parse_number, split_function,
+        ColorSpace, ParseCssError,
+    };
+    use crate::{
+        chromatic_adaptation::AdaptInto,
+        white_point::{D50, D65},
+        Alpha, Lab, Lch, Oklab, Oklch, Srgb, Xyz,
+    };
+
+    #[test]
+    fn detect_space() {
+        assert_eq!(detect_css_space("oklch(...)"), Some(ColorSpace::Oklch));
+        assert_eq!(detect_css_space(" OKLab (1 2 3)"), Some(ColorSpace::Oklab));
+        assert_eq!(detect_css_space("lab(1 2 3)"), Some(ColorSpace::Lab));
+        assert_eq!(
+            detect_css_space("rgba(1, 2, 3, 0.5)"),
+            Some(ColorSpace::Srgb)
+        );
+        assert_eq!(detect_css_space("#abc"), Some(ColorSpace::Srgb));
+        assert_eq!(
+            detect_css_space("hsla(1, 2%, 3%, 0.5)"),
+            Some(ColorSpace::Hsl)
+        );
+        assert_eq!(detect_css_space("oklcha(1 2 3)"), None);
+        assert_eq!(detect_css_space("oklch"), None);
+        assert_eq!(detect_css_space("red"), None);
+    }
+
+    #[test]
+    fn color_mix() {
+        let gray: Oklab = parse_color_mix::<Oklab>("color-mix(in oklab, #fff, #000)")
+            .unwrap()
+            .color;
+        assert_relative_eq!(gray, Oklab::new(0.5, 0.0, 0.0), epsilon = 0.0001);
+
+        let weighted: Alpha<Oklab, f32> =
+            parse_color_mix("color-mix(in oklab, 25% oklab(1 0 0), oklab(0 0.1 -0.1))").unwrap();
+        assert_relative_eq!(
+            weighted.color,
+            Oklab::new(0.25, 0.075, -0.075),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(weighted.alpha, 1.0);
+
+        let transparent: Alpha<Oklab, f32> =
+            parse_color_mix("color-mix(in lab, lab(50 0 0) 20%, lab(50 0 0) 20%)").unwrap();
+        assert_relative_eq!(transparent.alpha, 0.4, epsilon = 0.0001);
+
+        let scaled: Alpha<Oklab, f32> =
+            parse_color_mix("color-mix(in oklab, oklab(1 0 0) 100%, oklab(0 0 0) 100%)").unwrap();
+        assert_relative_eq!(scaled.color.l, 0.5, epsilon = 0.0001);
+        assert_relative_eq!(scaled.alpha, 1.0);
+
+        let parse = parse_color_mix::<Oklab>;
+        assert_eq!(
+            parse("mix(in oklab, #fff, #000)"),
+            Err(ParseCssError::UnexpectedFunction("color-mix"))
+        );
+        assert_eq!(
+            parse("color-mix(in xyz, #fff, #000)"),
+            Err(ParseCssError::UnknownColor)
+        );
+        assert_eq!(
+            parse("color-mix(in oklab, #fff)"),
+            Err(ParseCssError::ArgumentCount)
+        );
+        assert_eq!(
+            parse("color-mix(in oklab, #fff, #000, #888)"),
+            Err(ParseCssError::ArgumentCount)
+        );
+        assert_eq!(
+            parse("color-mix(in oklab, #fff 0%, #000 0%)"),
+            Err(ParseCssError::InvalidComponent)
+        );
+        assert_eq!(
+            parse("color-mix(in oklab, #fff 150%, #000)"),
+            Err(ParseCssError::InvalidComponent)
+        );
+        assert_eq!(
+            parse("color-mix(in oklab, rgb(1 2), #000)"),
+            Err(ParseCssError::ComponentCount)
+        );
+    }
+
+    #[test]
+    fn color_mix_rgb() {
+        let mixed: Alpha<Srgb, f32> =
+            parse_color_mix("color-mix(in srgb, rgb(255 0 0), rgba(0%, 0%, 100%))").unwrap();
+        assert_relative_eq!(mixed.color, Srgb::new(0.5, 0.0, 0.5), epsilon = 0.0001);
+
+        let mixed: Alpha<Srgb, f32> =
+            parse_color_mix("color-mix(in oklab, RGB(51 102 153), #336699)").unwrap();
+        assert_relative_eq!(mixed.color, Srgb::new(0.2, 0.4, 0.6), epsilon = 0.0001);
+    }
+
+    #[test]
+    fn color_mix_d50() {
+        let mixed: Alpha<Xyz<D65, f32>, f32> =
+            parse_color_mix("color-mix(in lab, lab(50 40 0), lab(50 0 40))").unwrap();
+        let mixed: Lab<D50, f32> = mixed.color.adapt_into();
+        assert_relative_eq!(mixed, Lab::new(50.0, 20.0, 20.0), epsilon = 0.01);
+
+        let mixed: Alpha<Xyz<D65, f32>, f32> =
+            parse_color_mix("color-mix(in lch, lch(50 40 0), lch(70 20 90))").unwrap();
+        let mixed: Lch<D50, f32> = mixed.color.adapt_into();
+        assert_relative_eq!(mixed, Lch::new(60.0, 30.0, 45.0), epsilon = 0.01);
+
+        let gray: Alpha<Lab<D65, f32>, f32> =
+            parse_color_mix("color-mix(in oklab, lab(50 0 0), lab(50 0 0))").unwrap();
+        assert_relative_eq!(gray.color, Lab::new(50.0, 0.0, 0.0), epsilon = 0.01);
+    }
+
+    #[test]
+    fn color_mix_alpha() {
+        // The example from CSS Color 5.
+        let mixed: Alpha<Srgb, f32> =
+            parse_color_mix("color-mix(in srgb, rgb(100% 0% 0% / 0.7) 25%, rgb(0% 100% 0% / 0.2))")
+                .unwrap();
+        assert_relative_eq!(
+            mixed.color,
+            Srgb::new(0.5385, 0.4615, 0.0),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(mixed.alpha, 0.325, epsilon = 0.0001);
+
+        // Hues are not weighed by alpha.
+        let mixed: Alpha<Oklch, f32> =
+            parse_color_mix("color-mix(in oklch, oklch(0.6 0.2 0 / 0.5), oklch(0.8 0.1 90))")
+                .unwrap();
+        assert_relative_eq!(mixed.color.l, 0.7333, epsilon = 0.0001);
+        assert_relative_eq!(mixed.color.chroma, 0.1333, epsilon = 0.0001);
+        assert_relative_eq!(
+            mixed.color.hue.into_positive_degrees(),
+            45.0,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(mixed.alpha, 0.75, epsilon = 0.0001);
+
+        let transparent: Alpha<Srgb, f32> =
+            parse_color_mix("color-mix(in srgb, rgba(255, 0, 0, 0) 50%, #000 30%)").unwrap();
+        assert_relative_eq!(transparent.color, Srgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(transparent.alpha, 0.3, epsilon = 0.0001);
+    }
+
+    #[cfg(feature = "named_from_str")]
+    #[test]
+    fn color_mix_named() {
+        use crate::{IntoColor, Oklch};
+
+        let mixed = parse_color_mix::<Oklch>("color-mix(in oklch, red 40%, Blue)").unwrap();
+        let red: Oklch = crate::Srgb::new(1.0, 0.0, 0.0).into_color();
+        let blue: Oklch = crate::Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        assert_relative_eq!(mixed.alpha, 1.0);
+        assert_relative_eq!(mixed.color.l, red.l * 0.4 + blue.l * 0.6, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn function() {
+        let lab = &["lab"];
+        assert_eq!(
+            split_function("lab(1 2 3)", lab),
+            Ok((["1", "2", "3"], None))
+        );
+        assert_eq!(
+            split_function("  LAB ( 1,2 , 3 ) ", lab),
+            Ok((["1", "2", "3"], None))
+        );
+        assert_eq!(
+            split_function("lch(1 2 3)", lab),
+            Err(ParseCssError::UnexpectedFunction("lab"))
+        );
+        assert_eq!(
+            split_function("oklab(1 2 3)", lab),
+            Err(ParseCssError::UnexpectedFunction("lab"))
+        );
+        assert_eq!(
+            split_function("lab(1 2)", lab),
+            Err(ParseCssError::ComponentCount)
+        );
+        assert_eq!(
+            split_function("hsla(1 2 3)", &["hsl", "hsla"]),
+            Ok((["1", "2", "3"], None))
+        );
+    }
+
+    #[test]
+    fn function_alpha() {
+        let lab = &["lab"];
+        assert_eq!(
+            split_function("lab(1 2 3 / 0.5)", lab),
+            Ok((["1", "2", "3"], Some("0.5")))
+        );
+        assert_eq!(
+            split_function("lab(1, 2, 3, 50%)", lab),
+            Ok((["1", "2", "3"], Some("50%")))
+        );
+        assert_eq!(
+            split_function("lab(1 2 3 0.5)", lab),
+            Err(ParseCssError::ComponentCount)
+        );
+        assert_eq!(
+            split_function("lab(1 2 3 /)", lab),
+            Err(ParseCssError::ComponentCount)
+        );
+        assert_eq!(
+            split_function("lab(1 2 3 / 0.5 0.5)", lab),
+            Err(ParseCssError::ComponentCount)
+        );
+
+        assert_eq!(
+            parse_function::<Lab<D50, f64>>("lab(1 2 3 / 0.5)").err(),
+            Some(ParseCssError::ComponentCount)
+        );
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(parse_number("12.5", 100.0), Ok(12.5));
+        assert_eq!(parse_number("50%", 0.4), Ok(0.2));
+        assert_eq!(parse_number("none", 100.0), Ok(0.0));
+        assert_eq!(
+            parse_number("inf", 100.0),
+            Err(ParseCssError::InvalidComponent)
+        );
+        assert_eq!(
+            parse_number("50deg", 100.0),
+            Err(ParseCssError::InvalidComponent)
+        );
+
+        assert_eq!(parse_hue("120"), Ok(120.0));
+        assert_eq!(parse_hue("120deg"), Ok(120.0));
+        assert_eq!(parse_hue("100grad"), Ok(90.0));
+        assert_eq!(parse_hue("0.5turn"), Ok(180.0));
+        assert!((parse_hue("3.14159265rad").unwrap() - 180.0).abs() < 0.0001);
+        assert_eq!(parse_hue("50%"), Err(ParseCssError::InvalidComponent));
+    }
+}
@@ -0,0 +1,155 @@
+//! Human readable descriptions of colors.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! The descriptions are made from a small vocabulary of lightness, chroma and
+//! hue words, such as "dark muted blue" or "very light gray". They are coarse
+//! by design, which makes them suitable for accessibility tools and voice
+//! interfaces, where a precise name is less important than a recognizable one.
+
+use crate::{
+    angle::{RealAngle, UnsignedAngle},
+    convert::IntoColor,
+    Oklch,
+};
+
+/// The hue names, with the [`Oklch`] hue (in degrees) where each of them
+/// starts. The last one wraps around to the first.
+const HUES: [(f64, &str); 8] = [
+    (45.0, "orange"),
+    (90.0, "yellow"),
+    (125.0, "green"),
+    (175.0, "cyan"),
+    (230.0, "blue"),
+    (280.0, "purple"),
+    (315.0, "magenta"),
+    (345.0, "red"),
+];
+
+/// Colors with less chroma than this are described as shades of gray.
+const GRAY_CHROMA: f64 = 0.025;
+
+/// Describe a color with a few words, such as "dark muted blue".
+///
+/// The color is converted to [`Oklch`] and its lightness, chroma and hue are
+/// sorted into buckets, each with its own word:
+///
+/// * Lightness: "very dark", "dark", (nothing), "light" and "very light".
+/// * Chroma: "muted", (nothing) and "vivid".
+/// * Hue: "red", "orange", "yellow", "green", "cyan", "blue", "purple" and
+///   "magenta". Dark orange colors are called "brown".
+///
+/// Colors with very little chroma are described as "black", "white" or
+/// "gray", where "gray" may have a lightness word in front of it.
+///
+/// ```
+/// use palette::{description::describe, Srgb};
+///
+/// assert_eq!(describe(Srgb::new(0.0f32, 0.0, 1.0)), "vivid blue");
+/// assert_eq!(describe(Srgb::new(0.15f32, 0.18, 0.3)), "dark muted blue");
+/// assert_eq!(describe(Srgb::new(0.5f32, 0.5, 0.5)), "gray");
+/// assert_eq!(describe(Srgb::new(1.0f32, 1.0, 1.0)), "white");
+/// ```
+pub fn describe<C, T>(color: C) -> String
+where
+    C: IntoColor<Oklch<T>>,
+    T: RealAngle + UnsignedAngle + Into<f64>,
+{
+    let color = color.into_color();
+    let lightness: f64 = color.l.into();
+    let chroma: f64 = color.chroma.into();
+    let hue: f64 = color.hue.into_positive_degrees().into();
+
+    if chroma < GRAY_CHROMA {
+        return if lightness < 0.15 {
+            "black".into()
+        } else if lightness > 0.97 {
+            "white".into()
+        } else {
+            join(describe_lightness(lightness), None, "gray")
+        };
+    }
+
+    let chroma_word = if chroma < 0.08 {
+        Some("muted")
+    } else if chroma < 0.2 {
+        None
+    } else {
+        Some("vivid")
+    };
+
+    let mut hue_word = HUES
+        .iter()
+        .rev()
+        .find(|&&(start, _)| hue >= start)
+        .map_or("red", |&(_, name)| name);
+
+    if hue_word == "orange" && lightness < 0.6 {
+        hue_word = "brown";
+    }
+
+    join(describe_lightness(lightness), chroma_word, hue_word)
+}
+
+fn describe_lightness(lightness: f64) -> Option<&'static str> {
+    if lightness < 0.3 {
+        Some("very dark")
+    } else if lightness < 0.45 {
+        Some("dark")
+    } else if lightness < 0.75 {
+        None
+    } else if lightness < 0.88 {
+        Some("light")
+    } else {
+        Some("very light")
+    }
+}
+
+fn join(lightness: Option<&str>, chroma: Option<&str>, hue: &str) -> String {
+    let words: Vec<&str> = lightness
+        .into_iter()
+        .chain(chroma)
+        .chain(Some(hue))
+        .collect();
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::describe;
+    use crate::Srgb;
+
+    #[test]
+    fn dark_muted_blue() {
+        let description = describe(Srgb::new(0.2f32, 0.23, 0.35));
+
+        assert!(description.contains("dark"), "{}", description);
+        assert!(description.contains("blue"), "{}", description);
+        assert!(description.contains("muted"), "{}", description);
+    }
+
+    #[test]
+    fn primaries() {
+        assert_eq!(describe(Srgb::new(1.0f32, 0.0, 0.0)), "vivid red");
+        assert_eq!(describe(Srgb::new(0.0f32, 1.0, 0.0)), "light vivid green");
+        assert_eq!(describe(Srgb::new(0.0f32, 0.0, 1.0)), "vivid blue");
+        assert_eq!(
+            describe(Srgb::new(1.0f32, 1.0, 0.0)),
+            "very light vivid yellow"
+        );
+    }
+
+    #[test]
+    fn grays() {
+        assert_eq!(describe(Srgb::new(0.0f32, 0.0, 0.0)), "black");
+        assert_eq!(describe(Srgb::new(0.1f32, 0.1, 0.1)), "very dark gray");
+        assert_eq!(describe(Srgb::new(0.8f32, 0.8, 0.8)), "light gray");
+        assert_eq!(describe(Srgb::new(1.0f32, 1.0, 1.0)), "white");
+    }
+
+    #[test]
+    fn brown() {
+        assert_eq!(describe(Srgb::new(0.55f32, 0.27, 0.07)), "brown");
+    }
+}
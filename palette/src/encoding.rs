@@ -1,11 +1,19 @@
 //! Various encoding traits, types and standards.
 
+pub use self::adobe_rgb::AdobeRgb1998;
+pub use self::display_p3::DisplayP3;
 pub use self::gamma::{F2p2, Gamma};
 pub use self::linear::Linear;
+pub use self::prophoto_rgb::ProPhotoRgb;
+pub use self::rec2020::Rec2020;
 pub use self::srgb::Srgb;
 
+pub mod adobe_rgb;
+pub mod display_p3;
 pub mod gamma;
 pub mod linear;
+pub mod prophoto_rgb;
+pub mod rec2020;
 pub mod srgb;
 
 /// A transfer function to and from linear space.
@@ -18,3 +26,33 @@ pub trait TransferFn<T>: 'static {
     #[must_use]
     fn into_linear(x: T) -> T;
 }
+
+/// Build a lookup table for decoding 8 bit components to linear `f32`, using
+/// the transfer function `E`.
+///
+/// Looking up each component in the table is a lot faster than calculating
+/// the transfer function when decoding many colors, such as the pixels of an
+/// image. The values are calculated with `f64` precision before they are
+/// rounded to `f32`.
+///
+/// ```
+/// use palette::encoding::{build_decode_lut, Rec2020};
+///
+/// let lut = build_decode_lut::<Rec2020>();
+///
+/// assert_eq!(lut[0], 0.0);
+/// assert_eq!(lut[255], 1.0);
+/// ```
+#[must_use]
+pub fn build_decode_lut<E>() -> [f32; 256]
+where
+    E: TransferFn<f64>,
+{
+    let mut lut = [0.0; 256];
+
+    for (value, entry) in lut.iter_mut().enumerate() {
+        *entry = E::into_linear(value as f64 / 255.0) as f32;
+    }
+
+    lut
+}
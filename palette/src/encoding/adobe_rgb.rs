@@ -0,0 +1,109 @@
+//! The Adobe RGB (1998) standard.
+
+use crate::{
+    encoding::TransferFn,
+    num::{Powf, Real, Recip},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, WhitePoint, D65},
+    Yxy,
+};
+
+/// The gamma of Adobe RGB (1998), which is `563/256`, or approximately 2.2.
+const GAMMA: f64 = 563.0 / 256.0;
+
+/// The Adobe RGB (1998) color space.
+///
+/// Adobe RGB has a wider gamut than sRGB, mainly in the greens and cyans, and
+/// uses the D65 white point with a pure power law transfer function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdobeRgb1998;
+
+impl<T: Real> Primaries<T> for AdobeRgb1998 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.6400),
+            T::from_f64(0.3300),
+            T::from_f64(0.297377),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.2100),
+            T::from_f64(0.7100),
+            T::from_f64(0.627349),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1500),
+            T::from_f64(0.0600),
+            T::from_f64(0.075274),
+        )
+    }
+}
+
+impl<T> RgbSpace<T> for AdobeRgb1998
+where
+    AdobeRgb1998: Primaries<T>,
+    D65: WhitePoint<T>,
+{
+    type Primaries = AdobeRgb1998;
+    type WhitePoint = D65;
+}
+
+impl<T> RgbStandard<T> for AdobeRgb1998
+where
+    AdobeRgb1998: RgbSpace<T> + TransferFn<T>,
+{
+    type Space = AdobeRgb1998;
+    type TransferFn = AdobeRgb1998;
+}
+
+impl<T> TransferFn<T> for AdobeRgb1998
+where
+    T: Real + Powf + Recip,
+{
+    #[inline]
+    fn into_linear(x: T) -> T {
+        x.powf(T::from_f64(GAMMA))
+    }
+
+    #[inline]
+    fn from_linear(x: T) -> T {
+        x.powf(T::from_f64(GAMMA).recip())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{encoding::TransferFn, matrix::rgb_to_xyz_matrix};
+
+    use super::AdobeRgb1998;
+
+    #[rustfmt::skip]
+    #[test]
+    fn rgb_to_xyz() {
+        let expected = [
+            0.5767309, 0.1855540, 0.1881852,
+            0.2973769, 0.6273491, 0.0752741,
+            0.0270343, 0.0706872, 0.9911085,
+        ];
+
+        let computed = rgb_to_xyz_matrix::<AdobeRgb1998, f64>();
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn transfer_fn_round_trip() {
+        for &value in &[0.0, 0.001, 0.01, 0.1, 0.5, 0.9, 1.0] {
+            let encoded: f64 = AdobeRgb1998::from_linear(value);
+            assert_relative_eq!(
+                AdobeRgb1998::into_linear(encoded),
+                value,
+                epsilon = 0.000001
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! The Display P3 standard.
+
+use crate::{
+    encoding::{Srgb, TransferFn},
+    num::{Arithmetics, One, Powf, Real, Recip},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, WhitePoint, D65},
+    Yxy,
+};
+
+/// The Display P3 color space.
+///
+/// Display P3 has the DCI-P3 primaries, which cover a wider gamut than sRGB,
+/// with the D65 white point and the same transfer function as sRGB. It's used
+/// by many modern displays.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayP3;
+
+impl<T: Real> Primaries<T> for DisplayP3 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.6800),
+            T::from_f64(0.3200),
+            T::from_f64(0.229004),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.2650),
+            T::from_f64(0.6900),
+            T::from_f64(0.691727),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1500),
+            T::from_f64(0.0600),
+            T::from_f64(0.079270),
+        )
+    }
+}
+
+impl<T> RgbSpace<T> for DisplayP3
+where
+    DisplayP3: Primaries<T>,
+    D65: WhitePoint<T>,
+{
+    type Primaries = DisplayP3;
+    type WhitePoint = D65;
+}
+
+impl<T> RgbStandard<T> for DisplayP3
+where
+    DisplayP3: RgbSpace<T> + TransferFn<T>,
+{
+    type Space = DisplayP3;
+    type TransferFn = DisplayP3;
+}
+
+impl<T> TransferFn<T> for DisplayP3
+where
+    T: Real + One + Powf + Recip + Arithmetics + PartialOrd,
+{
+    #[inline]
+    fn into_linear(x: T) -> T {
+        Srgb::into_linear(x)
+    }
+
+    #[inline]
+    fn from_linear(x: T) -> T {
+        Srgb::from_linear(x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::IntoColorUnclamped, encoding::TransferFn, matrix::rgb_to_xyz_matrix, rgb::Rgb,
+        Lch, Srgb,
+    };
+
+    use super::DisplayP3;
+
+    #[rustfmt::skip]
+    #[test]
+    fn rgb_to_xyz() {
+        // The published matrix is derived from the chromaticity of D65,
+        // which is a bit different from the white point in this crate.
+        let expected = [
+            0.4865709, 0.2656677, 0.1982173,
+            0.2289746, 0.6917385, 0.0792869,
+            0.0000000, 0.0451134, 1.0439444,
+        ];
+
+        let computed = rgb_to_xyz_matrix::<DisplayP3, f64>();
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn transfer_fn_round_trip() {
+        for &value in &[0.0, 0.001, 0.01, 0.1, 0.5, 0.9, 1.0] {
+            let encoded: f64 = DisplayP3::from_linear(value);
+            assert_relative_eq!(DisplayP3::into_linear(encoded), value, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn red_is_more_saturated_than_srgb() {
+        let p3_red: Lch<_, f64> = Rgb::<DisplayP3, f64>::new(1.0, 0.0, 0.0).into_color_unclamped();
+        let srgb_red: Lch<_, f64> = Srgb::new(1.0f64, 0.0, 0.0).into_color_unclamped();
+
+        assert!(p3_red.chroma > srgb_red.chroma);
+    }
+}
@@ -0,0 +1,109 @@
+//! The ProPhoto RGB standard.
+
+use crate::{
+    encoding::TransferFn,
+    num::{Arithmetics, Powf, Real, Recip},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, WhitePoint, D50},
+    Yxy,
+};
+
+/// The ProPhoto RGB color space, also known as ROMM RGB.
+///
+/// ProPhoto RGB has a very wide gamut, where the green and blue primaries are
+/// outside of the visible colors. It uses the D50 white point and a gamma of
+/// 1.8, with a short linear segment near black.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProPhotoRgb;
+
+impl<T: Real> Primaries<T> for ProPhotoRgb {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.7347),
+            T::from_f64(0.2653),
+            T::from_f64(0.288040),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1596),
+            T::from_f64(0.8404),
+            T::from_f64(0.711874),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.0366),
+            T::from_f64(0.0001),
+            T::from_f64(0.000086),
+        )
+    }
+}
+
+impl<T> RgbSpace<T> for ProPhotoRgb
+where
+    ProPhotoRgb: Primaries<T>,
+    D50: WhitePoint<T>,
+{
+    type Primaries = ProPhotoRgb;
+    type WhitePoint = D50;
+}
+
+impl<T> RgbStandard<T> for ProPhotoRgb
+where
+    ProPhotoRgb: RgbSpace<T> + TransferFn<T>,
+{
+    type Space = ProPhotoRgb;
+    type TransferFn = ProPhotoRgb;
+}
+
+impl<T> TransferFn<T> for ProPhotoRgb
+where
+    T: Real + Powf + Recip + Arithmetics + PartialOrd,
+{
+    fn into_linear(x: T) -> T {
+        if x < T::from_f64(1.0 / 32.0) {
+            x * T::from_f64(16.0).recip()
+        } else {
+            x.powf(T::from_f64(1.8))
+        }
+    }
+
+    fn from_linear(x: T) -> T {
+        if x < T::from_f64(1.0 / 512.0) {
+            x * T::from_f64(16.0)
+        } else {
+            x.powf(T::from_f64(1.8).recip())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{encoding::TransferFn, matrix::rgb_to_xyz_matrix};
+
+    use super::ProPhotoRgb;
+
+    #[rustfmt::skip]
+    #[test]
+    fn rgb_to_xyz() {
+        let expected = [
+            0.7976749, 0.1351917, 0.0313534,
+            0.2880402, 0.7118741, 0.0000857,
+            0.0000000, 0.0000000, 0.8252100,
+        ];
+
+        let computed = rgb_to_xyz_matrix::<ProPhotoRgb, f64>();
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn transfer_fn_round_trip() {
+        for &value in &[0.0, 0.001, 0.01, 0.1, 0.5, 0.9, 1.0] {
+            let encoded: f64 = ProPhotoRgb::from_linear(value);
+            assert_relative_eq!(ProPhotoRgb::into_linear(encoded), value, epsilon = 0.000001);
+        }
+    }
+}
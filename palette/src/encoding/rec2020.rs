@@ -0,0 +1,116 @@
+//! The ITU-R BT.2020 standard.
+
+use crate::{
+    encoding::TransferFn,
+    num::{Arithmetics, Powf, Real, Recip},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, WhitePoint, D65},
+    Yxy,
+};
+
+// The constants of the transfer function, at full precision.
+const ALPHA: f64 = 1.099_296_826_809_44;
+const BETA: f64 = 0.018_053_968_510_807;
+
+/// The ITU-R BT.2020 (Rec. 2020) color space.
+///
+/// Rec. 2020 is the color space of ultra high definition television, with
+/// monochromatic primaries that cover a very wide gamut. It uses the D65
+/// white point and the BT.2020 transfer function for standard dynamic range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020;
+
+impl<T: Real> Primaries<T> for Rec2020 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.7080),
+            T::from_f64(0.2920),
+            T::from_f64(0.262722),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1700),
+            T::from_f64(0.7970),
+            T::from_f64(0.677989),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1310),
+            T::from_f64(0.0460),
+            T::from_f64(0.059289),
+        )
+    }
+}
+
+impl<T> RgbSpace<T> for Rec2020
+where
+    Rec2020: Primaries<T>,
+    D65: WhitePoint<T>,
+{
+    type Primaries = Rec2020;
+    type WhitePoint = D65;
+}
+
+impl<T> RgbStandard<T> for Rec2020
+where
+    Rec2020: RgbSpace<T> + TransferFn<T>,
+{
+    type Space = Rec2020;
+    type TransferFn = Rec2020;
+}
+
+impl<T> TransferFn<T> for Rec2020
+where
+    T: Real + Powf + Recip + Arithmetics + PartialOrd,
+{
+    fn into_linear(x: T) -> T {
+        if x < T::from_f64(4.5 * BETA) {
+            x * T::from_f64(4.5).recip()
+        } else {
+            ((x + T::from_f64(ALPHA - 1.0)) * T::from_f64(ALPHA).recip())
+                .powf(T::from_f64(0.45).recip())
+        }
+    }
+
+    fn from_linear(x: T) -> T {
+        if x < T::from_f64(BETA) {
+            x * T::from_f64(4.5)
+        } else {
+            T::from_f64(ALPHA) * x.powf(T::from_f64(0.45)) - T::from_f64(ALPHA - 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{encoding::TransferFn, matrix::rgb_to_xyz_matrix};
+
+    use super::Rec2020;
+
+    #[rustfmt::skip]
+    #[test]
+    fn rgb_to_xyz() {
+        // The published matrix is derived from the chromaticity of D65,
+        // which is a bit different from the white point in this crate.
+        let expected = [
+            0.6369580, 0.1446169, 0.1688810,
+            0.2627002, 0.6779981, 0.0593017,
+            0.0000000, 0.0280727, 1.0609851,
+        ];
+
+        let computed = rgb_to_xyz_matrix::<Rec2020, f64>();
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn transfer_fn_round_trip() {
+        for &value in &[0.0, 0.001, 0.01, 0.1, 0.5, 0.9, 1.0] {
+            let encoded: f64 = Rec2020::from_linear(value);
+            assert_relative_eq!(Rec2020::into_linear(encoded), value, epsilon = 0.000001);
+        }
+    }
+}
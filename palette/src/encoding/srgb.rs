@@ -84,3 +84,36 @@ where
         }
     }
 }
+
+include!(concat!(env!("OUT_DIR"), "/lut.rs"));
+
+/// Decode an 8 bit sRGB component to linear `f32`, using a precomputed lookup
+/// table instead of calculating it.
+#[inline]
+pub(crate) fn u8_into_linear_f32(value: u8) -> f32 {
+    SRGB_U8_TO_LINEAR_F32[usize::from(value)]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::encoding::{build_decode_lut, TransferFn};
+
+    use super::{u8_into_linear_f32, Srgb};
+
+    #[test]
+    fn lookup_table_matches_transfer_function() {
+        for value in 0..=255u8 {
+            let expected: f64 = Srgb::into_linear(f64::from(value) / 255.0);
+            assert_relative_eq!(u8_into_linear_f32(value), expected as f32);
+        }
+    }
+
+    #[test]
+    fn generic_lookup_table_matches() {
+        let lut = build_decode_lut::<Srgb>();
+
+        for value in 0..=255u8 {
+            assert_relative_eq!(lut[usize::from(value)], u8_into_linear_f32(value));
+        }
+    }
+}
@@ -0,0 +1,518 @@
+//! Tools for working with the edges of RGB gamuts.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use crate::{
+    angle::RealAngle,
+    clamp,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding,
+    num::{Arithmetics, Real, Zero},
+    rgb::{Rgb, RgbStandard},
+    Clamp, FromColor, Mix, Oklab, OklabHue, Oklch,
+};
+
+/// A lookup table of the highest chroma that stays within the sRGB gamut, for
+/// each lightness and hue in [`Oklch`].
+///
+/// Finding the edge of the gamut is relatively slow, so the table is computed
+/// once, on a grid of lightness and hue values, and then looked up as many
+/// times as needed. This is useful for color pickers, where the chroma slider
+/// should stop at the edge of the gamut.
+///
+/// Values between the grid points are interpolated, which makes them
+/// approximate. The edge of the gamut is curved, so a finer grid gives more
+/// accurate results.
+///
+/// ```
+/// use palette::{gamut::MaxChromaTable, Oklch};
+///
+/// let table = MaxChromaTable::new(100, 360);
+///
+/// // Limit the chroma of a color from a picker to what sRGB can show.
+/// let mut color = Oklch::new(0.7f32, 0.4, 140.0);
+/// color.chroma = color.chroma.min(table.max_chroma(color.l, color.hue));
+///
+/// assert!(color.chroma > 0.1 && color.chroma < 0.4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxChromaTable<T = f32> {
+    lightness_steps: usize,
+    hue_steps: usize,
+    chroma: Vec<T>,
+}
+
+impl<T> MaxChromaTable<T>
+where
+    T: Real + RealAngle + Clone + Into<f64>,
+{
+    /// Compute the table, with `lightness_steps` steps between lightness `0.0`
+    /// and `1.0`, and `hue_steps` steps around the hue circle.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either number of steps is `0`.
+    pub fn new(lightness_steps: usize, hue_steps: usize) -> Self {
+        assert!(
+            lightness_steps > 0 && hue_steps > 0,
+            "a MaxChromaTable needs at least one lightness and hue step"
+        );
+
+        let mut chroma = Vec::with_capacity((lightness_steps + 1) * hue_steps);
+
+        for l in 0..=lightness_steps {
+            let l = l as f64 / lightness_steps as f64;
+
+            for hue in 0..hue_steps {
+                let hue = hue as f64 * 360.0 / hue_steps as f64;
+                let max_chroma = find_max_chroma::<encoding::Srgb, _>(l, hue, MAX_RGB_CHROMA);
+                chroma.push(T::from_f64(max_chroma));
+            }
+        }
+
+        MaxChromaTable {
+            lightness_steps,
+            hue_steps,
+            chroma,
+        }
+    }
+
+    /// Look up the highest chroma that stays within the sRGB gamut, for the
+    /// lightness `l` and `hue`.
+    ///
+    /// The lightness is clamped to the range `0.0..=1.0`. The result is
+    /// weighted from the four closest entries in the table.
+    #[must_use]
+    pub fn max_chroma<H: Into<OklabHue<T>>>(&self, l: T, hue: H) -> T {
+        let l = clamp(l.into(), 0.0, 1.0) * self.lightness_steps as f64;
+        let hue: f64 = hue.into().into_raw_degrees().into();
+        let hue = hue.rem_euclid(360.0) / 360.0 * self.hue_steps as f64;
+
+        let l_low = (l.floor() as usize).min(self.lightness_steps);
+        let l_high = (l_low + 1).min(self.lightness_steps);
+        let l_weight = l - l_low as f64;
+
+        let hue_low = (hue.floor() as usize) % self.hue_steps;
+        let hue_high = (hue_low + 1) % self.hue_steps;
+        let hue_weight = hue - hue.floor();
+
+        let get =
+            |l: usize, hue: usize| -> f64 { self.chroma[l * self.hue_steps + hue].clone().into() };
+        let low = get(l_low, hue_low) * (1.0 - hue_weight) + get(l_low, hue_high) * hue_weight;
+        let high = get(l_high, hue_low) * (1.0 - hue_weight) + get(l_high, hue_high) * hue_weight;
+
+        T::from_f64(low * (1.0 - l_weight) + high * l_weight)
+    }
+}
+
+/// Generate a palette of `count` colors that are in harmony with `base`, and
+/// that can all be shown in the RGB standard `S`.
+///
+/// The hues are evenly spaced around the hue circle, starting with the hue of
+/// `base`, so two colors give a complementary pair and three colors give a
+/// triad. The lightness alternates around the lightness of `base`, to make
+/// neighboring colors easier to tell apart. All colors get the chroma of
+/// `base`, unless that would take them outside the gamut of `S`, in which case
+/// they get the highest chroma on the surface of the gamut instead.
+///
+/// ```
+/// use palette::{encoding::DisplayP3, gamut::harmonious_palette, Oklch};
+///
+/// let base = Oklch::new(0.6, 0.2, 30.0);
+/// let triad = harmonious_palette::<DisplayP3, _>(base, 3);
+///
+/// assert_eq!(triad.len(), 3);
+/// assert_eq!(triad[0].hue, base.hue);
+/// assert_eq!(triad[1].hue, base.hue + 120.0);
+/// ```
+pub fn harmonious_palette<S, T>(base: Oklch<T>, count: usize) -> Vec<Oklch<T>>
+where
+    S: RgbStandard<f64>,
+    Rgb<S, f64>: FromColorUnclamped<Oklch<f64>>,
+    T: Real + RealAngle + Clone + Into<f64>,
+{
+    const LIGHTNESS_OFFSETS: [f64; 3] = [0.0, 0.1, -0.1];
+
+    let base_l: f64 = base.l.into();
+    let base_chroma: f64 = base.chroma.into();
+    let base_hue: f64 = base.hue.into_raw_degrees().into();
+
+    (0..count)
+        .map(|i| {
+            let hue = base_hue + i as f64 * 360.0 / count as f64;
+            let l = clamp(base_l + LIGHTNESS_OFFSETS[i % 3], 0.1, 0.95);
+            let max_chroma = find_max_chroma::<S, _>(l, hue, MAX_RGB_CHROMA);
+
+            Oklch::new(
+                T::from_f64(l),
+                T::from_f64(base_chroma.min(max_chroma)),
+                T::from_f64(hue),
+            )
+        })
+        .collect()
+}
+
+/// Reduce the chroma of `color` to make it fit in the gamut of the RGB
+/// standard `S`, while keeping its lightness and hue.
+///
+/// Colors that already fit are returned unchanged. This is different from
+/// converting to RGB and clamping the components, which also changes the
+/// lightness and hue. It's useful for color pickers, where the chroma slider
+/// is the only one that should be limited. The lightness has to be within
+/// `0.0..=1.0` for the color to fit, so colors outside that range get no
+/// chroma.
+///
+/// ```
+/// use palette::{encoding::Srgb, gamut::clamp_chroma, Oklch};
+///
+/// let color = Oklch::new(0.7, 0.4, 150.0);
+/// let clamped = clamp_chroma::<Srgb, _>(color);
+///
+/// assert_eq!((clamped.l, clamped.hue), (color.l, color.hue));
+/// assert!(clamped.chroma < color.chroma);
+/// ```
+#[must_use]
+pub fn clamp_chroma<S, T>(color: Oklch<T>) -> Oklch<T>
+where
+    S: RgbStandard<f64>,
+    Rgb<S, f64>: FromColorUnclamped<Oklch<f64>>,
+    T: Real + RealAngle + Clone + Into<f64>,
+{
+    let max_chroma = find_max_chroma::<S, _>(
+        color.l.clone().into(),
+        color.hue.clone().into_raw_degrees().into(),
+        MAX_RGB_CHROMA,
+    );
+
+    if color.chroma.clone().into() <= max_chroma {
+        color
+    } else {
+        Oklch::new(color.l, T::from_f64(max_chroma), color.hue)
+    }
+}
+
+/// Find the most saturated color in the RGB standard `S`, for the given `hue`
+/// and Oklab `lightness`.
+///
+/// This is the color with the highest chroma in [`Oklch`] that stays inside
+/// the gamut of `S`, which is useful for getting the most vivid version of an
+/// accent color. The result is on the surface of the gamut, for lightness
+/// values between `0.0` and `1.0`.
+///
+/// ```
+/// use palette::{encoding::Srgb, gamut::max_chroma_color};
+///
+/// let vivid_green = max_chroma_color::<Srgb, f32, _>(140.0, 0.8);
+///
+/// assert!(vivid_green.green > vivid_green.red);
+/// assert!(vivid_green.green > vivid_green.blue);
+/// ```
+pub fn max_chroma_color<S, T, H>(hue: H, lightness: T) -> Rgb<S, T>
+where
+    S: RgbStandard<f64>,
+    Rgb<S, f64>: FromColorUnclamped<Oklch<f64>>,
+    T: Real + RealAngle + Into<f64>,
+    H: Into<OklabHue<T>>,
+{
+    let l = clamp(lightness.into(), 0.0, 1.0);
+    let hue = hue.into().into_raw_degrees().into();
+    let chroma = find_max_chroma::<S, _>(l, hue, MAX_RGB_CHROMA);
+
+    let color = Rgb::<S, f64>::from_color_unclamped(Oklch::new(l, chroma, hue)).clamp();
+    Rgb::new(
+        T::from_f64(color.red),
+        T::from_f64(color.green),
+        T::from_f64(color.blue),
+    )
+}
+
+/// Mix `color` toward `target` in [`Oklab`], and fit the result within the
+/// bounds of `C`.
+///
+/// An `amount` of `0.0` gives `color` and `1.0` gives `target`. Mixing in
+/// Oklab gives perceptually even steps, which is useful for building scales
+/// of colors by tinting a base color toward white, black or a brand color.
+/// The mix may end up a bit outside the RGB gamut, even when both colors are
+/// inside it, so the result is clamped, the same way as with
+/// [`FromColor`].
+///
+/// ```
+/// use palette::{gamut::tint_toward, Srgb};
+///
+/// let brand = Srgb::new(0.1f32, 0.3, 0.8);
+/// let white = Srgb::new(1.0, 1.0, 1.0);
+///
+/// let scale: Vec<Srgb> = (1..=4)
+///     .map(|step| tint_toward(brand, white, step as f32 * 0.2))
+///     .collect();
+/// assert!(scale.iter().all(|color| color.red > brand.red));
+/// ```
+pub fn tint_toward<C, T>(color: C, target: C, amount: T) -> C
+where
+    C: IntoColorUnclamped<Oklab<T>> + FromColor<Oklab<T>>,
+    Oklab<T>: Mix<Scalar = T>,
+{
+    let color: Oklab<T> = color.into_color_unclamped();
+    C::from_color(color.mix(target.into_color_unclamped(), amount))
+}
+
+/// An upper bound for the Oklch chroma of every supported RGB gamut with the
+/// D65 white point. The most saturated of them is the green primary of
+/// Rec. 2020, at a chroma of about `0.47`.
+const MAX_RGB_CHROMA: f64 = 0.5;
+
+/// Find the highest chroma, up to `max_chroma`, that stays within the gamut of
+/// `S` for the lightness `l` and `hue`, with a binary search.
+pub(crate) fn find_max_chroma<S, T>(l: T, hue: T, max_chroma: T) -> T
+where
+    T: Real + Zero + Arithmetics + PartialOrd + Clone,
+    Rgb<S, T>: FromColorUnclamped<Oklch<T>>,
+{
+    // Enough to get within 0.00000003 of the largest chroma for a chroma of
+    // MAX_RGB_CHROMA.
+    const SEARCH_STEPS: usize = 24;
+
+    let is_in_gamut =
+        |chroma: T| is_in_rgb_gamut::<S, T>(Oklch::new(l.clone(), chroma, hue.clone()));
+
+    if is_in_gamut(max_chroma.clone()) {
+        return max_chroma;
+    }
+
+    let mut low = T::zero();
+    let mut high = max_chroma;
+
+    for _ in 0..SEARCH_STEPS {
+        let middle = (low.clone() + &high) / T::from_f64(2.0);
+
+        if is_in_gamut(middle.clone()) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+fn is_in_rgb_gamut<S, T>(color: Oklch<T>) -> bool
+where
+    T: Real + PartialOrd,
+    Rgb<S, T>: FromColorUnclamped<Oklch<T>>,
+{
+    // Allow a bit of rounding error, so white and black are in the gamut.
+    const TOLERANCE: f64 = 1e-7;
+
+    let Rgb {
+        red, green, blue, ..
+    } = Rgb::<S, T>::from_color_unclamped(color);
+
+    let range = T::from_f64(-TOLERANCE)..=T::from_f64(1.0 + TOLERANCE);
+    [red, green, blue]
+        .iter()
+        .all(|component| range.contains(component))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        clamp_chroma, harmonious_palette, is_in_rgb_gamut, max_chroma_color, tint_toward,
+        MaxChromaTable,
+    };
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{DisplayP3, Srgb},
+        rgb::Rgb,
+        FromColor, IsWithinBounds, Oklab, Oklch,
+    };
+
+    #[test]
+    fn max_chroma_is_the_edge() {
+        // The steps are powers of two, to look up exactly at the grid points.
+        let table = MaxChromaTable::new(16, 32);
+
+        for l in 1..16 {
+            let l = l as f32 / 16.0;
+
+            for hue in 0..32 {
+                let hue = hue as f32 * 11.25;
+                let chroma = f64::from(table.max_chroma(l, hue));
+                let (l, hue) = (f64::from(l), f64::from(hue));
+
+                assert!(is_in_rgb_gamut::<Srgb, _>(Oklch::new(l, chroma, hue)));
+                assert!(!is_in_rgb_gamut::<Srgb, _>(Oklch::new(
+                    l,
+                    chroma + 0.001,
+                    hue
+                )));
+            }
+        }
+    }
+
+    #[test]
+    fn black_and_white_have_no_chroma() {
+        let table = MaxChromaTable::new(10, 12);
+
+        for hue in 0..12 {
+            let hue = hue as f32 * 30.0;
+            assert!(table.max_chroma(0.0, hue) < 0.01);
+            assert!(table.max_chroma(1.0, hue) < 0.01);
+            assert!(table.max_chroma(1.5, hue) < 0.01);
+        }
+    }
+
+    #[test]
+    fn f64_table_matches_f32_table() {
+        let table = MaxChromaTable::<f32>::new(10, 12);
+        let table_f64 = MaxChromaTable::<f64>::new(10, 12);
+
+        for &(l, hue) in [(0.3, 15.0), (0.55, 200.0), (0.8, -40.0)].iter() {
+            let chroma = table.max_chroma(l as f32, hue as f32);
+            assert_relative_eq!(
+                table_f64.max_chroma(l, hue),
+                f64::from(chroma),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn interpolated_values_are_close_to_the_edge() {
+        let table = MaxChromaTable::new(100, 360);
+
+        for &(l, hue) in &[(0.55f32, 25.5f32), (0.705, 142.3), (0.333, 264.9)] {
+            let chroma = f64::from(table.max_chroma(l, hue));
+            let (l, hue) = (f64::from(l), f64::from(hue));
+
+            assert!(is_in_rgb_gamut::<Srgb, _>(Oklch::new(
+                l,
+                chroma - 0.005,
+                hue
+            )));
+            assert!(!is_in_rgb_gamut::<Srgb, _>(Oklch::new(
+                l,
+                chroma + 0.005,
+                hue
+            )));
+        }
+    }
+
+    #[test]
+    fn harmonious_palette_is_in_gamut() {
+        let base = Oklch::new(0.7f32, 0.3, 200.0);
+        let colors = harmonious_palette::<DisplayP3, _>(base, 5);
+
+        assert_eq!(colors.len(), 5);
+        for (i, color) in colors.iter().enumerate() {
+            let hue_difference = (color.hue - base.hue).into_positive_degrees();
+            assert!((hue_difference - i as f32 * 72.0).abs() < 0.01);
+
+            // The chroma was rounded to `f32`, so it may be very slightly out.
+            let color = Oklch::new(
+                f64::from(color.l),
+                f64::from(color.chroma) - 1e-5,
+                f64::from(color.hue.into_degrees()),
+            );
+            assert!(is_in_rgb_gamut::<DisplayP3, _>(color));
+        }
+
+        // The surface of the gamut limits the chroma of at least some hues.
+        assert!(colors.iter().any(|color| color.chroma < base.chroma));
+
+        let gray = harmonious_palette::<DisplayP3, _>(Oklch::new(0.5, 0.0, 0.0), 3);
+        assert!(gray.iter().all(|color| color.chroma == 0.0));
+
+        let colors_f64 = harmonious_palette::<DisplayP3, _>(Oklch::new(0.7f64, 0.3, 200.0), 5);
+        for (color, color_f64) in colors.iter().zip(&colors_f64) {
+            assert_relative_eq!(color_f64.chroma, f64::from(color.chroma), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn clamp_chroma_only_changes_chroma() {
+        let color = Oklch::new(0.6f32, 0.35, 30.0);
+        let clamped = clamp_chroma::<Srgb, _>(color);
+
+        assert_eq!(clamped.l, color.l);
+        assert_eq!(clamped.hue, color.hue);
+        assert!(clamped.chroma < color.chroma);
+
+        let (l, chroma, hue) = (0.6, f64::from(clamped.chroma), 30.0);
+        assert!(is_in_rgb_gamut::<Srgb, _>(Oklch::new(l, chroma, hue)));
+        assert!(!is_in_rgb_gamut::<Srgb, _>(Oklch::new(
+            l,
+            chroma + 0.001,
+            hue
+        )));
+
+        // The wider gamut allows more chroma.
+        assert!(clamp_chroma::<DisplayP3, _>(color).chroma > clamped.chroma);
+
+        let inside = Oklch::new(0.6f32, 0.05, 30.0);
+        assert_eq!(clamp_chroma::<Srgb, _>(inside), inside);
+
+        // Other component types are clamped the same way.
+        let clamped_f64 = clamp_chroma::<Srgb, _>(Oklch::new(0.6f64, 0.35, 30.0));
+        assert_relative_eq!(clamped_f64.chroma, chroma, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn max_chroma_color_is_on_the_surface() {
+        for &(hue, l) in &[(20.0f32, 0.6f32), (140.0, 0.8), (265.0, 0.45), (330.0, 0.7)] {
+            let color = max_chroma_color::<Srgb, f32, _>(hue, l);
+            let components = [color.red, color.green, color.blue];
+
+            assert!(components.iter().any(|c| !(0.0001..=0.9999).contains(c)));
+
+            let oklch = Oklch::<f64>::from_color_unclamped(Rgb::<Srgb, f64>::new(
+                f64::from(color.red),
+                f64::from(color.green),
+                f64::from(color.blue),
+            ));
+            assert!((oklch.l - f64::from(l)).abs() < 0.001);
+            assert!(!is_in_rgb_gamut::<Srgb, _>(Oklch::new(
+                oklch.l,
+                oklch.chroma + 0.002,
+                oklch.hue
+            )));
+
+            let color_f64 = max_chroma_color::<Srgb, f64, _>(f64::from(hue), f64::from(l));
+            assert_relative_eq!(color_f64.into_format(), color, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn tint_toward_white() {
+        let base = crate::Srgb::new(0.6f32, 0.1, 0.3);
+        let white = crate::Srgb::new(1.0, 1.0, 1.0);
+
+        let mut previous = Oklab::from_color(base);
+        for step in 1..=5 {
+            let amount = step as f32 * 0.2;
+            let tinted = tint_toward(base, white, amount);
+            assert!(tinted.is_within_bounds());
+
+            let oklab = Oklab::from_color(tinted);
+            assert!(oklab.l > previous.l);
+
+            let expected = previous.l + (1.0 - Oklab::from_color(base).l) * 0.2;
+            assert!((oklab.l - expected).abs() < 0.001);
+            previous = oklab;
+        }
+
+        assert_relative_eq!(tint_toward(base, white, 0.0), base, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn tint_toward_clamps_to_gamut() {
+        // These mix outside the sRGB gamut in Oklab.
+        let blue = crate::Srgb::new(0.0f32, 0.0, 1.0);
+        let yellow = crate::Srgb::new(1.0, 1.0, 0.0);
+
+        for step in 0..=10 {
+            let tinted = tint_toward(blue, yellow, step as f32 * 0.1);
+            assert!(tinted.is_within_bounds());
+        }
+    }
+}
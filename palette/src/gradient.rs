@@ -8,13 +8,22 @@ use core::{cmp::max, marker::PhantomData};
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use crate::{
-    clamp, clamp_min,
-    num::{Arithmetics, One, Real, Zero},
-    Mix,
+    cast, clamp, clamp_min,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding,
+    gamut::find_max_chroma,
+    num::{Arithmetics, IsValidDivisor, One, Real, Sqrt, Zero},
+    rgb::{FromHexError, Rgb},
+    stimulus::FromStimulus,
+    white_point::D65,
+    Alpha, Clamp, ColorDifference, FromColor, Lab, Mix, Oklab, Oklch, Srgb,
 };
 
+pub use self::timeline::{Easing, Timeline};
+
 #[cfg(feature = "named_gradients")]
 pub mod named;
+mod timeline;
 
 impl<C, T> From<T> for Gradient<C, T>
 where
@@ -50,6 +59,21 @@ where
         C: Clone,
         C::Scalar: Arithmetics + PartialOrd + Clone,
         T: AsRef<[(C::Scalar, C)]>,
+    {
+        self.interpolate(i, |min_color, max_color, factor| {
+            min_color.clone().mix(max_color.clone(), factor)
+        })
+    }
+
+    /// Find the control points around `i`, and mix them with `mix`. The color
+    /// of the closest control point will be returned if `i` is outside the
+    /// domain.
+    fn interpolate<F>(&self, i: C::Scalar, mix: F) -> C
+    where
+        C: Clone,
+        C::Scalar: Arithmetics + PartialOrd + Clone,
+        T: AsRef<[(C::Scalar, C)]>,
+        F: FnOnce(&C, &C, C::Scalar) -> C,
     {
         let (min, min_color) = self
             .0
@@ -95,7 +119,53 @@ where
 
         let factor = (i - min) / (max.clone() - min);
 
-        min_color.clone().mix(max_color.clone(), factor)
+        mix(min_color, max_color, factor)
+    }
+
+    /// Get a color from the gradient, using `mode` to decide what happens when
+    /// `i` is outside the domain.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{gradient::WrapMode, Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// assert_relative_eq!(gradient.get_wrapped(1.25, WrapMode::Clamp), LinSrgb::new(1.0, 1.0, 1.0));
+    /// assert_relative_eq!(gradient.get_wrapped(1.25, WrapMode::Repeat), LinSrgb::new(0.25, 0.25, 0.25));
+    /// assert_relative_eq!(gradient.get_wrapped(1.25, WrapMode::Mirror), LinSrgb::new(0.75, 0.75, 0.75));
+    /// ```
+    pub fn get_wrapped(&self, i: C::Scalar, mode: WrapMode) -> C
+    where
+        C: Clone,
+        C::Scalar: Zero + Arithmetics + PartialOrd + Clone,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let (min, max) = self.domain();
+        let length = max - &min;
+
+        if length <= C::Scalar::zero() {
+            return self.get(i);
+        }
+
+        let i = match mode {
+            WrapMode::Clamp => i,
+            WrapMode::Repeat => min.clone() + wrap(i - &min, length),
+            WrapMode::Mirror => {
+                let offset = wrap(i - &min, length.clone() + &length);
+
+                if offset > length {
+                    min + (length.clone() + &length - offset)
+                } else {
+                    min + offset
+                }
+            }
+        };
+
+        self.get(i)
     }
 
     /// Create a gradient of colors with custom spacing and domain. There must
@@ -156,6 +226,162 @@ where
         }
     }
 
+    /// Split the gradient into `n` equally wide bands, and get one flat
+    /// color for each band.
+    ///
+    /// Each band gets the color from its middle, so the result looks like a
+    /// posterized version of the gradient. This is different from
+    /// [`take`](Gradient::take), which includes the colors at both ends.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    /// let bands = gradient.stepped(4);
+    ///
+    /// assert_eq!(bands.len(), 4);
+    /// assert_relative_eq!(bands[0], LinSrgb::new(0.125, 0.125, 0.125));
+    /// assert_relative_eq!(bands[3], LinSrgb::new(0.875, 0.875, 0.875));
+    /// ```
+    pub fn stepped(&self, n: usize) -> Vec<C>
+    where
+        C: Clone,
+        C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let (min, max) = self.domain();
+        let band_width = (max - &min) / C::Scalar::from_f64(n as f64);
+
+        (0..n)
+            .map(|band| {
+                let middle = C::Scalar::from_f64(band as f64 + 0.5);
+                self.get(min.clone() + middle * &band_width)
+            })
+            .collect()
+    }
+
+    /// Sample `width` evenly spaced colors from the gradient, as interleaved
+    /// 8 bit RGB components.
+    ///
+    /// This is the layout of a one dimensional `RGB8` texture, so the result
+    /// can be uploaded to the GPU as a color map, for example with
+    /// `glTexImage1D`. The colors are converted to the RGB standard `S`, such
+    /// as [`encoding::Srgb`](crate::encoding::Srgb), and sampled the same way
+    /// as with [`take`](Gradient::take).
+    ///
+    /// ```
+    /// use palette::{encoding::Srgb, Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 0.5, 0.0),
+    /// ]);
+    /// let bytes = gradient.to_texture_bytes::<Srgb>(256);
+    ///
+    /// assert_eq!(bytes.len(), 256 * 3);
+    /// assert_eq!(&bytes[bytes.len() - 3..], &[255, 188, 0]);
+    /// ```
+    pub fn to_texture_bytes<S>(&self, width: usize) -> Vec<u8>
+    where
+        C: Clone,
+        C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+        Rgb<S, C::Scalar>: FromColor<C>,
+        u8: FromStimulus<C::Scalar>,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let colors: Vec<Rgb<S, u8>> = self
+            .take(width)
+            .map(|color| Rgb::<S, C::Scalar>::from_color(color).into_format())
+            .collect();
+
+        cast::into_component_vec(colors)
+    }
+
+    /// Bake `size` evenly spaced colors from the gradient into a lookup
+    /// table of 8 bit sRGB colors.
+    ///
+    /// The colors are sampled the same way as with
+    /// [`take`](Gradient::take), so the table starts and ends with the ends
+    /// of the gradient. The table can be serialized, with the `serializing`
+    /// feature, which makes it possible to ship the gradient as a color map
+    /// in an asset file.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Srgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 0.5, 0.0),
+    /// ]);
+    /// let lut = gradient.bake_lut(256);
+    ///
+    /// assert_eq!(lut.len(), 256);
+    /// assert_eq!(lut.sample(1.0), Srgb::new(255, 188, 0));
+    /// ```
+    pub fn bake_lut(&self, size: usize) -> GradientLut
+    where
+        C: Clone,
+        C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+        Srgb<C::Scalar>: FromColor<C>,
+        u8: FromStimulus<C::Scalar>,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        GradientLut {
+            colors: self
+                .take(size)
+                .map(|color| Srgb::<C::Scalar>::from_color(color).into_format())
+                .collect(),
+        }
+    }
+
+    /// Sample `n` evenly spaced colors from the gradient, as the list of
+    /// color stops for a CSS `conic-gradient`.
+    ///
+    /// The colors are sampled the same way as with
+    /// [`take`](Gradient::take), and written as sRGB hex codes. Their angles
+    /// go from `0deg` for the start of the gradient to `360deg` for the end,
+    /// so the whole gradient goes around the circle once. This is useful for
+    /// exporting hue wheels and other circular color pickers.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    /// ]);
+    /// let stops = gradient.to_conic_css_stops(3);
+    ///
+    /// assert_eq!(stops, "#ff0000 0deg, #0000ff 180deg, #ff0000 360deg");
+    /// let css = format!("conic-gradient({})", stops);
+    /// ```
+    pub fn to_conic_css_stops(&self, n: usize) -> String
+    where
+        C: Clone,
+        C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+        Srgb<C::Scalar>: FromColor<C>,
+        u8: FromStimulus<C::Scalar>,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let last = max(n, 2) - 1;
+
+        self.take(n)
+            .enumerate()
+            .map(|(index, color)| {
+                let color: Srgb<u8> = Srgb::<C::Scalar>::from_color(color).into_format();
+                // Round the angle to avoid printing values like 119.99999.
+                let angle = (index as f64 * 36000.0 / last as f64).round() / 100.0;
+                format!("#{:x} {}deg", color, angle)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// Slice this gradient to limit its domain.
     pub fn slice<R: Into<Range<C::Scalar>>>(&self, range: R) -> Slice<C, T> {
         Slice {
@@ -164,6 +390,188 @@ where
         }
     }
 
+    /// Convert the control points to another color space, to make it
+    /// interpolate in that space instead.
+    ///
+    /// The positions stay the same, so the converted gradient has the same
+    /// colors at each control point, but the colors in between depend on the
+    /// new color space. This makes it possible to, for example, define a
+    /// gradient in sRGB and then interpolate it in [`Oklab`] for more even
+    /// perceived steps.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Oklab};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    ///     LinSrgb::new(1.0, 1.0, 0.0),
+    /// ]);
+    /// let perceptual = gradient.convert::<Oklab>();
+    ///
+    /// assert_eq!(perceptual.domain(), gradient.domain());
+    /// ```
+    pub fn convert<D>(&self) -> Gradient<D>
+    where
+        C: Clone,
+        C::Scalar: Clone,
+        D: Mix<Scalar = C::Scalar> + FromColor<C>,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        Gradient(
+            self.0
+                .as_ref()
+                .iter()
+                .map(|(position, color)| (position.clone(), D::from_color(color.clone())))
+                .collect(),
+            PhantomData,
+        )
+    }
+
+    /// Transform each control point with `f`, keeping their positions.
+    ///
+    /// The result may be in another color space, as long as it has the same
+    /// scalar type. Only the control points are transformed, so the colors in
+    /// between are still mixed from the transformed control points.
+    ///
+    /// ```
+    /// use palette::{Gradient, Lighten, Oklab};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     Oklab::new(0.2, 0.1, 0.0),
+    ///     Oklab::new(0.6, 0.0, 0.1),
+    /// ]);
+    /// let lighter = gradient.map(|color| color.lighten_fixed(0.1));
+    ///
+    /// assert_eq!(lighter.domain(), gradient.domain());
+    /// assert!(lighter.get(0.5).l > gradient.get(0.5).l);
+    /// ```
+    pub fn map<D, F>(&self, mut f: F) -> Gradient<D>
+    where
+        C: Clone,
+        C::Scalar: Clone,
+        D: Mix<Scalar = C::Scalar>,
+        F: FnMut(C) -> D,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        Gradient(
+            self.0
+                .as_ref()
+                .iter()
+                .map(|(position, color)| (position.clone(), f(color.clone())))
+                .collect(),
+            PhantomData,
+        )
+    }
+
+    /// Get the mean color of the gradient between `lo` and `hi`.
+    ///
+    /// The gradient is integrated over the range, one linear segment at a
+    /// time, so the result is exact when the color space mixes linearly. The
+    /// parts of the range that are outside the domain count as the color of
+    /// the closest control point, just like for [`get`][Gradient::get]. The
+    /// order of `lo` and `hi` doesn't matter, and an empty range gives the
+    /// color at `lo`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// assert_relative_eq!(gradient.average(0.0, 0.5), LinSrgb::new(0.25, 0.25, 0.25));
+    /// ```
+    pub fn average(&self, lo: C::Scalar, hi: C::Scalar) -> C
+    where
+        C: Clone,
+        C::Scalar: Real + Zero + Arithmetics + IsValidDivisor + PartialOrd + Clone,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let (lo, hi) = if hi < lo { (hi, lo) } else { (lo, hi) };
+
+        let mut average = self.get(lo.clone());
+        let mut total = C::Scalar::zero();
+        let mut add_segment = |from: C::Scalar, to: C::Scalar| {
+            let length = to.clone() - &from;
+            total = total.clone() + &length;
+
+            if total.is_valid_divisor() {
+                // The gradient is linear within a segment, so its mean is the
+                // color in the middle.
+                let middle = self.get((from + to) / C::Scalar::from_f64(2.0));
+                average = average.clone().mix(middle, length / &total);
+            }
+        };
+
+        let mut from = lo.clone();
+        for (position, _) in self.0.as_ref() {
+            if *position > lo && *position < hi {
+                add_segment(from, position.clone());
+                from = position.clone();
+            }
+        }
+        add_segment(from, hi);
+
+        average
+    }
+
+    /// Find visible steps in the gradient, by sampling it at `n` evenly spaced
+    /// points and comparing each sample with the one before it.
+    ///
+    /// The position halfway between two samples is returned when their CIEDE2000
+    /// color difference, measured in [`Lab`] with the D65 white point, is
+    /// greater than `threshold`. A threshold of about 2.3 is roughly the
+    /// smallest difference that can be noticed. This is useful for checking a
+    /// gradient before baking it into a lookup table with `n` entries.
+    ///
+    /// ```
+    /// use palette::{Gradient, Oklab};
+    ///
+    /// let gradient = Gradient::with_domain(vec![
+    ///     (0.0, Oklab::new(0.0, 0.0, 0.0)),
+    ///     (0.5, Oklab::new(0.4, 0.0, 0.0)),
+    ///     (0.5, Oklab::new(0.6, 0.0, 0.0)),
+    ///     (1.0, Oklab::new(1.0, 0.0, 0.0)),
+    /// ]);
+    ///
+    /// let banding: Vec<f32> = gradient.detect_banding(256, 2.3);
+    /// assert_eq!(banding.len(), 1);
+    /// assert!((banding[0] - 0.5).abs() < 0.01);
+    /// ```
+    pub fn detect_banding(&self, n: usize, threshold: C::Scalar) -> Vec<C::Scalar>
+    where
+        C: Clone + IntoColorUnclamped<Lab<D65, <C as Mix>::Scalar>>,
+        C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+        Lab<D65, C::Scalar>: ColorDifference<Scalar = C::Scalar>,
+        T: AsRef<[(C::Scalar, C)]>,
+    {
+        let mut banding = Vec::new();
+
+        if n < 2 {
+            return banding;
+        }
+
+        let (min, max) = self.domain();
+        let step = (max - &min) / C::Scalar::from_f64((n - 1) as f64);
+        let half_step = step.clone() / C::Scalar::from_f64(2.0);
+
+        let mut previous: Lab<D65, C::Scalar> = self.get(min.clone()).into_color_unclamped();
+        for i in 1..n {
+            let position = min.clone() + step.clone() * C::Scalar::from_f64(i as f64);
+            let current: Lab<D65, C::Scalar> = self.get(position.clone()).into_color_unclamped();
+
+            if previous.get_color_difference(current.clone()) > threshold {
+                banding.push(position - &half_step);
+            }
+
+            previous = current;
+        }
+
+        banding
+    }
+
     /// Get the limits of this gradient's domain.
     pub fn domain(&self) -> (C::Scalar, C::Scalar)
     where
@@ -184,6 +592,398 @@ where
     }
 }
 
+impl<C, T> Gradient<Alpha<C, C::Scalar>, T>
+where
+    C: Mix + Clone,
+    C::Scalar: Zero + One + Arithmetics + PartialOrd + Clone,
+    T: AsRef<[(C::Scalar, Alpha<C, C::Scalar>)]>,
+{
+    /// Get a color from the gradient, where the color part is mixed in the
+    /// color space `D`, while the alpha is mixed linearly.
+    ///
+    /// This makes it possible to, for example, get an even perceptual
+    /// transition between the colors by mixing them in [`Oklab`], while
+    /// keeping a constant rate of fading. The colors of the control points
+    /// are converted to `D` for each mix, so converting the whole gradient
+    /// with [`convert`](Gradient::convert) is faster if the colors don't have
+    /// to stay in their original space. The color of the closest control
+    /// point will be returned if `i` is outside the domain.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, LinSrgba, Oklab};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgba::new(0.0, 0.0, 0.0, 0.0),
+    ///     LinSrgba::new(1.0, 1.0, 1.0, 1.0),
+    /// ]);
+    /// let color = gradient.get_with_color_space::<Oklab>(0.25);
+    ///
+    /// assert_relative_eq!(color.alpha, 0.25);
+    /// // Oklab is darker than linear RGB at the dark end.
+    /// assert!(color.red < 0.25);
+    /// ```
+    pub fn get_with_color_space<D>(&self, i: C::Scalar) -> Alpha<C, C::Scalar>
+    where
+        D: Mix<Scalar = C::Scalar> + FromColorUnclamped<C>,
+        C: FromColor<D>,
+    {
+        self.interpolate(i, |start, end, factor| {
+            let start_color = D::from_color_unclamped(start.color.clone());
+            let end_color = D::from_color_unclamped(end.color.clone());
+            let alpha = start.alpha.clone() + factor.clone() * (end.alpha.clone() - &start.alpha);
+
+            Alpha {
+                color: C::from_color(start_color.mix(end_color, factor)),
+                alpha,
+            }
+        })
+    }
+}
+
+impl<C, T> Gradient<C, T>
+where
+    C: Mix + Clone + IntoColorUnclamped<Oklab<<C as Mix>::Scalar>>,
+    C::Scalar: Real + Zero + Sqrt + Arithmetics + IsValidDivisor + PartialOrd + Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Reduce the number of control points to at most `max_stops`, while
+    /// keeping the shape of the gradient as intact as possible.
+    ///
+    /// The first and last control points are always kept, even if
+    /// `max_stops` is less than two. The remaining
+    /// points are added back one by one in a Douglas-Peucker-like fashion,
+    /// where the point that is the furthest away from the simplified gradient
+    /// goes first. The distance is measured in [`Oklab`], to make it reflect
+    /// the perceived difference. Points stop being added when there are
+    /// `max_stops` of them, or when none of the remaining points are further
+    /// away than `tolerance`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.5, 0.0, 0.5),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    ///     LinSrgb::new(0.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// // The second point lies on the line between the first and the third,
+    /// // so it can be removed without changing the gradient.
+    /// let simplified = gradient.simplify(3, 0.001);
+    ///
+    /// for (original, simplified) in gradient.take(10).zip(simplified.take(10)) {
+    ///     assert_relative_eq!(original, simplified, epsilon = 0.0001);
+    /// }
+    /// ```
+    pub fn simplify(&self, max_stops: usize, tolerance: C::Scalar) -> Gradient<C> {
+        let stops = self.0.as_ref();
+        let last = stops.len() - 1;
+
+        let mut kept = vec![0];
+        if last > 0 {
+            kept.push(last);
+        }
+
+        while kept.len() < max_stops {
+            let mut furthest: Option<(usize, usize, C::Scalar)> = None;
+
+            for (segment, window) in kept.windows(2).enumerate() {
+                let (start_position, start_color) = &stops[window[0]];
+                let (end_position, end_color) = &stops[window[1]];
+                let span = end_position.clone() - start_position;
+
+                for (index, (position, color)) in
+                    stops.iter().enumerate().take(window[1]).skip(window[0] + 1)
+                {
+                    let factor = if span.is_valid_divisor() {
+                        (position.clone() - start_position) / &span
+                    } else {
+                        C::Scalar::zero()
+                    };
+                    let interpolated = start_color.clone().mix(end_color.clone(), factor);
+                    let distance = oklab_distance(color.clone(), interpolated);
+
+                    let is_further = match &furthest {
+                        Some((_, _, furthest)) => distance > *furthest,
+                        None => true,
+                    };
+
+                    if is_further {
+                        furthest = Some((segment + 1, index, distance));
+                    }
+                }
+            }
+
+            match furthest {
+                Some((insert_at, index, distance)) if distance > tolerance => {
+                    kept.insert(insert_at, index)
+                }
+                _ => break,
+            }
+        }
+
+        Gradient(
+            kept.into_iter().map(|index| stops[index].clone()).collect(),
+            PhantomData,
+        )
+    }
+
+    /// Get a color from the gradient, mixed in [`Oklab`], while keeping the
+    /// chroma at or above `min_chroma`.
+    ///
+    /// Mixing complementary colors, such as red and cyan, in Oklab takes a
+    /// straight path that passes close to gray in the middle. The parts of
+    /// the path that are closer to gray than `min_chroma` are pushed out to
+    /// that distance, so the path arcs around the gray axis instead of
+    /// crossing it. The limit is lowered for segments that start or end with
+    /// a lower chroma, to not change the control points. The color of the
+    /// closest control point will be returned if `i` is outside the domain.
+    ///
+    /// ```
+    /// use palette::{Gradient, IntoColor, LinSrgb, Oklch};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// let middle: Oklch = gradient.get_with_min_chroma(0.5, 0.05).into_color();
+    /// assert!(middle.chroma > 0.04);
+    /// ```
+    pub fn get_with_min_chroma(&self, i: C::Scalar, min_chroma: C::Scalar) -> C
+    where
+        C: FromColor<Oklab<<C as Mix>::Scalar>>,
+        Oklab<C::Scalar>: Mix<Scalar = C::Scalar>,
+    {
+        let chroma = |color: &Oklab<C::Scalar>| {
+            (color.a.clone() * &color.a + color.b.clone() * &color.b).sqrt()
+        };
+
+        self.interpolate(i, |start, end, factor| {
+            let start: Oklab<C::Scalar> = start.clone().into_color_unclamped();
+            let end: Oklab<C::Scalar> = end.clone().into_color_unclamped();
+
+            let mut limit = min_chroma;
+            for end_chroma in [chroma(&start), chroma(&end)].iter() {
+                if *end_chroma < limit {
+                    limit = end_chroma.clone();
+                }
+            }
+
+            let mut color = start.mix(end, factor);
+            let color_chroma = chroma(&color);
+            if color_chroma < limit && color_chroma.is_valid_divisor() {
+                let scale = limit / color_chroma;
+                color.a = color.a * &scale;
+                color.b = color.b * scale;
+            }
+
+            C::from_color(color)
+        })
+    }
+
+    /// Measure the perceptual length of the gradient, to be able to sample it
+    /// by distance instead of by position.
+    ///
+    /// The gradient is divided into `resolution` evenly spaced steps, and the
+    /// distance in [`Oklab`] is added up along them. The result can then be
+    /// sampled with [`ArcLength::sample_by_arclength`], where evenly spaced
+    /// arc lengths give colors that are evenly spaced perceptually, even if
+    /// the control points aren't. A higher resolution is more accurate.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    /// let arc_length = gradient.arc_length(256);
+    ///
+    /// // The dark end of linear RGB has larger steps, so the perceptual
+    /// // middle is closer to the start.
+    /// let middle = arc_length.sample_by_arclength(0.5);
+    /// assert!(middle.red < 0.3);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `resolution` is `0`.
+    pub fn arc_length(&self, resolution: usize) -> ArcLength<'_, C, T> {
+        assert!(resolution > 0, "the resolution must be at least 1");
+
+        let (min, max) = self.domain();
+        let step = (max - &min) / C::Scalar::from_f64(resolution as f64);
+
+        let mut table = Vec::with_capacity(resolution + 1);
+        let mut length = C::Scalar::zero();
+        let mut previous = self.get(min.clone());
+        table.push((min.clone(), length.clone()));
+
+        for i in 1..=resolution {
+            let position = min.clone() + step.clone() * C::Scalar::from_f64(i as f64);
+            let current = self.get(position.clone());
+            length = length + oklab_distance(previous, current.clone());
+            table.push((position, length.clone()));
+            previous = current;
+        }
+
+        ArcLength {
+            gradient: self,
+            table,
+        }
+    }
+
+    /// Insert a control point in the middle of each segment, with the color
+    /// that is halfway between its ends in [`Oklab`].
+    ///
+    /// Interpolating in a space that isn't perceptually uniform can make the
+    /// middle of a gradient look too dark, too bright or muddy. The classic
+    /// example is red to green, which passes through a dull brown when the
+    /// sRGB components are mixed directly. The inserted points pull the middle
+    /// of each segment to the perceptual midpoint, while the gradient keeps
+    /// interpolating in `C` between them.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, IntoColor, LinSrgb, Oklab};
+    ///
+    /// let red = LinSrgb::new(1.0, 0.0, 0.0);
+    /// let green = LinSrgb::new(0.0, 1.0, 0.0);
+    /// let fixed = Gradient::new(vec![red, green]).perceptual_midpoint_fix();
+    ///
+    /// let red: Oklab = red.into_color();
+    /// let green: Oklab = green.into_color();
+    /// let middle: Oklab = fixed.get(0.5).into_color();
+    /// assert_relative_eq!(middle.l, (red.l + green.l) / 2.0, epsilon = 0.001);
+    /// ```
+    pub fn perceptual_midpoint_fix(&self) -> Gradient<C>
+    where
+        C: FromColor<Oklab<<C as Mix>::Scalar>>,
+        Oklab<C::Scalar>: Mix<Scalar = C::Scalar>,
+    {
+        let stops = self.0.as_ref();
+        let mut fixed = Vec::with_capacity(stops.len() * 2);
+
+        for window in stops.windows(2) {
+            let (start_position, start_color) = &window[0];
+            let (end_position, end_color) = &window[1];
+
+            let start: Oklab<C::Scalar> = start_color.clone().into_color_unclamped();
+            let end: Oklab<C::Scalar> = end_color.clone().into_color_unclamped();
+            let half = C::Scalar::from_f64(0.5);
+
+            fixed.push(window[0].clone());
+            fixed.push((
+                (start_position.clone() + end_position.clone()) * &half,
+                C::from_color(start.mix(end, half)),
+            ));
+        }
+
+        fixed.extend(stops.last().cloned());
+
+        Gradient(fixed, PhantomData)
+    }
+
+    /// Adjust the lightness of the control points in [`Oklab`], so it never
+    /// decreases from one control point to the next.
+    ///
+    /// Sequential colormaps should have a lightness that increases along the
+    /// gradient, to not suggest features in the data that aren't there. Each
+    /// run of control points that breaks the order gets the average lightness
+    /// of the run, which is the smallest change in the least squares sense.
+    /// The `a` and `b` components are kept, so the hue and chroma stay the
+    /// same, unless the adjusted color has to be clamped to fit in `C`.
+    ///
+    /// Only the control points are adjusted, so the lightness between them is
+    /// only guaranteed to be monotonic when `C` is [`Oklab`]. Other color
+    /// spaces may bend the lightness slightly within each segment.
+    ///
+    /// ```
+    /// use palette::{Gradient, Oklab};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     Oklab::new(0.2, 0.1, 0.0),
+    ///     Oklab::new(0.6, 0.0, 0.1),
+    ///     Oklab::new(0.4, -0.1, 0.0),
+    ///     Oklab::new(0.9, 0.0, -0.1),
+    /// ]);
+    /// let fixed = gradient.enforce_monotonic_lightness();
+    ///
+    /// assert_eq!(fixed.get(1.0 / 3.0), Oklab::new(0.5, 0.0, 0.1));
+    /// assert_eq!(fixed.get(2.0 / 3.0), Oklab::new(0.5, -0.1, 0.0));
+    /// ```
+    pub fn enforce_monotonic_lightness(&self) -> Gradient<C>
+    where
+        C: FromColor<Oklab<<C as Mix>::Scalar>>,
+    {
+        let stops = self.0.as_ref();
+        let colors: Vec<Oklab<C::Scalar>> = stops
+            .iter()
+            .map(|(_, color)| color.clone().into_color_unclamped())
+            .collect();
+
+        // Pool adjacent violators, where each block is the sum of the
+        // lightness and the number of control points in it.
+        let mut blocks: Vec<(C::Scalar, usize)> = Vec::with_capacity(colors.len());
+        for color in &colors {
+            blocks.push((color.l.clone(), 1));
+
+            while blocks.len() > 1 {
+                let (sum, count) = blocks[blocks.len() - 1].clone();
+                let (previous_sum, previous_count) = blocks[blocks.len() - 2].clone();
+                let mean = sum.clone() / C::Scalar::from_f64(count as f64);
+                let previous_mean =
+                    previous_sum.clone() / C::Scalar::from_f64(previous_count as f64);
+
+                if previous_mean <= mean {
+                    break;
+                }
+
+                blocks.pop();
+                *blocks.last_mut().unwrap() = (previous_sum + sum, previous_count + count);
+            }
+        }
+
+        let lightness = blocks.into_iter().flat_map(|(sum, count)| {
+            let mean = sum / C::Scalar::from_f64(count as f64);
+            (0..count).map(move |_| mean.clone())
+        });
+
+        Gradient(
+            stops
+                .iter()
+                .zip(colors)
+                .zip(lightness)
+                .map(|(((position, _), color), l)| {
+                    (
+                        position.clone(),
+                        C::from_color(Oklab::new(l, color.a, color.b)),
+                    )
+                })
+                .collect(),
+            PhantomData,
+        )
+    }
+}
+
+fn oklab_distance<C, T>(a: C, b: C) -> T
+where
+    C: IntoColorUnclamped<Oklab<T>>,
+    T: Sqrt + Arithmetics + Clone,
+{
+    let a: Oklab<T> = a.into_color_unclamped();
+    let b: Oklab<T> = b.into_color_unclamped();
+    let l = a.l - b.l;
+    let a_diff = a.a - b.a;
+    let b_diff = a.b - b.b;
+
+    (l.clone() * l + a_diff.clone() * a_diff + b_diff.clone() * b_diff).sqrt()
+}
+
 impl<C> Gradient<C>
 where
     C: Mix,
@@ -202,6 +1002,355 @@ where
 
         Gradient(points, PhantomData)
     }
+
+    /// Create a gradient from `a` to `b`, with `stops` extra control points in
+    /// between, which are evenly spaced in [`Oklab`].
+    ///
+    /// The control points follow the perceptual interpolation between the
+    /// colors, so the gradient looks smooth even when it's interpolated in
+    /// the original color space. This is useful when the gradient is handed
+    /// to something that can only mix in sRGB, such as a CSS
+    /// `linear-gradient`. More stops follow the perceptual interpolation more
+    /// closely.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Gradient, IntoColor, LinSrgb, Oklab};
+    ///
+    /// let black = LinSrgb::new(0.0, 0.0, 0.0);
+    /// let white = LinSrgb::new(1.0, 1.0, 1.0);
+    /// let gradient = Gradient::two_color_perceptual(black, white, 3);
+    ///
+    /// let quarter: Oklab = gradient.get(0.25).into_color();
+    /// assert_relative_eq!(quarter.l, 0.25, epsilon = 0.001);
+    /// ```
+    pub fn two_color_perceptual(a: C, b: C, stops: usize) -> Gradient<C>
+    where
+        C: IntoColorUnclamped<Oklab<<C as Mix>::Scalar>> + FromColor<Oklab<<C as Mix>::Scalar>>,
+        C::Scalar: Clone,
+        Oklab<C::Scalar>: Mix<Scalar = C::Scalar> + Clone,
+    {
+        let a: Oklab<C::Scalar> = a.into_color_unclamped();
+        let b: Oklab<C::Scalar> = b.into_color_unclamped();
+        let last = C::Scalar::from_f64((stops + 1) as f64);
+
+        Gradient::new((0..=stops + 1).map(|i| {
+            let factor = C::Scalar::from_f64(i as f64) / last.clone();
+            C::from_color(a.clone().mix(b.clone(), factor))
+        }))
+    }
+}
+
+impl<C> Gradient<C>
+where
+    C: Mix,
+{
+    /// Add a control point with `color` at `position`, and return its index.
+    ///
+    /// The control points are kept sorted by their positions. A control point
+    /// that has the same position as an existing one is placed after it,
+    /// which makes the color jump at that position.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let mut gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    /// let index = gradient.insert_stop(0.5, LinSrgb::new(1.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(index, 1);
+    /// assert_eq!(gradient.get(0.5), LinSrgb::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn insert_stop(&mut self, position: C::Scalar, color: C) -> usize
+    where
+        C::Scalar: PartialOrd,
+    {
+        let index = self
+            .0
+            .iter()
+            .position(|(other_position, _)| *other_position > position)
+            .unwrap_or(self.0.len());
+        self.0.insert(index, (position, color));
+        index
+    }
+
+    /// Remove the control point at `index`, and return its position and
+    /// color.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds, or if it's the only control point.
+    pub fn remove_stop(&mut self, index: usize) -> (C::Scalar, C) {
+        assert!(
+            self.0.len() > 1,
+            "a gradient needs at least one control point"
+        );
+        self.0.remove(index)
+    }
+
+    /// Change the color of the control point at `index`, without moving it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_stop_color(&mut self, index: usize, color: C) {
+        self.0[index].1 = color;
+    }
+}
+
+impl<C> Gradient<C>
+where
+    C: Mix + FromColor<Srgb<<C as Mix>::Scalar>>,
+    C::Scalar: Real + FromStimulus<u8>,
+{
+    /// Create a gradient from a colormap definition, such as
+    /// `[[0.0, "#000000"], [0.5, "#ff0000"], [1.0, "#ffffff"]]`.
+    ///
+    /// The definition is a list of `(position, color)` pairs, where each
+    /// color is an sRGB hex code in any format that `Srgb<u8>` can be parsed
+    /// from. This is the format that colormaps are commonly shared as in JSON
+    /// files. The pairs may also be written with parentheses, like Python
+    /// tuples, and the colors may be quoted with `'` instead of `"`. The
+    /// control points are sorted by their positions, so they don't have to be
+    /// in order.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Srgb};
+    ///
+    /// let gradient = Gradient::<LinSrgb>::from_stops_str(
+    ///     r##"[[1.0, "#ffffff"], [0.0, "#000000"], [0.5, "#ff0000"]]"##,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(gradient.get(0.5), Srgb::new(1.0, 0.0, 0.0).into_linear());
+    /// assert_eq!(gradient.domain(), (0.0, 1.0));
+    /// ```
+    pub fn from_stops_str(stops: &str) -> Result<Self, FromStopsStrError> {
+        let mut rest = stops.trim();
+        rest = rest
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or(FromStopsStrError::InvalidFormat(
+                "expected a list in brackets",
+            ))?;
+
+        let mut points = Vec::new();
+
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+
+            let closing = if rest.starts_with('[') {
+                ']'
+            } else if rest.starts_with('(') {
+                ')'
+            } else {
+                return Err(FromStopsStrError::InvalidFormat(
+                    "expected a pair in brackets or parentheses",
+                ));
+            };
+            let end = rest
+                .find(closing)
+                .ok_or(FromStopsStrError::InvalidFormat("unclosed pair"))?;
+            let pair = &rest[1..end];
+            rest = rest[end + 1..].trim_start();
+            if !rest.is_empty() {
+                rest = rest
+                    .strip_prefix(',')
+                    .ok_or(FromStopsStrError::InvalidFormat(
+                        "expected a comma between pairs",
+                    ))?;
+            }
+
+            let separator = pair.find(',').ok_or(FromStopsStrError::InvalidFormat(
+                "expected a position and a color",
+            ))?;
+            let (position, color) = (&pair[..separator], &pair[separator + 1..]);
+            let position: f64 = position
+                .trim()
+                .parse()
+                .map_err(|_| FromStopsStrError::InvalidPosition)?;
+            if !position.is_finite() {
+                return Err(FromStopsStrError::InvalidPosition);
+            }
+
+            let color = color.trim();
+            let color = color
+                .strip_prefix('"')
+                .and_then(|color| color.strip_suffix('"'))
+                .or_else(|| {
+                    color
+                        .strip_prefix('\'')
+                        .and_then(|color| color.strip_suffix('\''))
+                })
+                .unwrap_or(color);
+            let color: Srgb<u8> = color.parse().map_err(FromStopsStrError::InvalidColor)?;
+
+            points.push((position, C::from_color(color.into_format())));
+        }
+
+        if points.is_empty() {
+            return Err(FromStopsStrError::Empty);
+        }
+
+        points.sort_by(|(a, _), (b, _)| a.partial_cmp(b).expect("positions should be finite"));
+
+        Ok(Gradient(
+            points
+                .into_iter()
+                .map(|(position, color)| (C::Scalar::from_f64(position), color))
+                .collect(),
+            PhantomData,
+        ))
+    }
+}
+
+/// The error type returned when parsing a colormap definition fails. See
+/// [`Gradient::from_stops_str`].
+#[derive(Debug)]
+pub enum FromStopsStrError {
+    /// The definition didn't follow the expected format.
+    InvalidFormat(&'static str),
+    /// A position was not a finite number.
+    InvalidPosition,
+    /// A color was not a valid hex code.
+    InvalidColor(FromHexError),
+    /// The definition didn't contain any control points.
+    Empty,
+}
+
+impl core::fmt::Display for FromStopsStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromStopsStrError::InvalidFormat(message) => {
+                write!(f, "invalid colormap definition: {}", message)
+            }
+            FromStopsStrError::InvalidPosition => f.write_str("invalid control point position"),
+            FromStopsStrError::InvalidColor(error) => {
+                write!(f, "invalid control point color: {}", error)
+            }
+            FromStopsStrError::Empty => f.write_str("the colormap definition is empty"),
+        }
+    }
+}
+
+impl std::error::Error for FromStopsStrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromStopsStrError::InvalidColor(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Create a ring of `steps` evenly spaced hues, with the same [`Oklch`]
+/// lightness and chroma.
+///
+/// This is the base of a perceptual color wheel, where all colors appear
+/// equally bright and colorful. The hues start at `0°` and go all the way
+/// around, without repeating the first one at the end. Colors that are
+/// outside the sRGB gamut get their chroma reduced until they fit, which
+/// keeps their lightness and hue intact.
+///
+/// ```
+/// use palette::{gradient::oklch_hue_wheel, IsWithinBounds};
+///
+/// let wheel = oklch_hue_wheel(0.7f32, 0.1, 12);
+///
+/// assert_eq!(wheel.len(), 12);
+/// assert!(wheel.iter().all(|color| color.is_within_bounds()));
+/// ```
+pub fn oklch_hue_wheel<T>(lightness: T, chroma: T, steps: usize) -> Vec<Srgb<T>>
+where
+    T: Real + Zero + Arithmetics + PartialOrd + Clone,
+    Srgb<T>: FromColorUnclamped<Oklch<T>> + Clamp,
+{
+    let step_size = T::from_f64(360.0) / T::from_f64(max(steps, 1) as f64);
+
+    (0..steps)
+        .map(|step| {
+            let hue = T::from_f64(step as f64) * &step_size;
+            let chroma = find_max_chroma::<encoding::Srgb, _>(
+                lightness.clone(),
+                hue.clone(),
+                chroma.clone(),
+            );
+
+            Srgb::from_color_unclamped(Oklch::new(lightness.clone(), chroma, hue)).clamp()
+        })
+        .collect()
+}
+
+/// Create an even rainbow of `steps` colors, for example for categorical
+/// palettes in data visualization.
+///
+/// The colors have evenly spaced [`Oklch`] hues, starting at `0°`, and the
+/// same lightness and chroma. The chroma is the highest one that all of the
+/// hues can have within the sRGB gamut, at a lightness of `0.75`, so none of
+/// the colors stand out as more colorful than the others. Use
+/// [`oklch_hue_wheel`] for choosing the lightness and chroma.
+///
+/// ```
+/// use palette::{gradient::rainbow, IsWithinBounds, Srgb};
+///
+/// let colors: Vec<Srgb> = rainbow(8);
+///
+/// assert_eq!(colors.len(), 8);
+/// assert!(colors.iter().all(|color| color.is_within_bounds()));
+/// ```
+pub fn rainbow<T>(steps: usize) -> Vec<Srgb<T>>
+where
+    T: Real + Zero + Arithmetics + PartialOrd + Clone,
+    Srgb<T>: FromColorUnclamped<Oklch<T>> + Clamp,
+{
+    // Higher than the sRGB gamut allows for any hue.
+    const CHROMA_LIMIT: f64 = 0.4;
+
+    let lightness = T::from_f64(0.75);
+    let step_size = T::from_f64(360.0) / T::from_f64(max(steps, 1) as f64);
+
+    let mut chroma = T::from_f64(CHROMA_LIMIT);
+    for step in 0..steps {
+        let hue = T::from_f64(step as f64) * &step_size;
+        chroma = find_max_chroma::<encoding::Srgb, _>(lightness.clone(), hue, chroma);
+    }
+
+    oklch_hue_wheel(lightness, chroma, steps)
+}
+
+/// Decides how positions outside the domain of a gradient are treated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Use the color of the closest control point. This is what
+    /// [`Gradient::get`] does.
+    Clamp,
+
+    /// Start over from the beginning of the domain, as if the gradient is
+    /// repeated end to end. The upper limit of the domain is considered to be
+    /// the start of the next repetition.
+    Repeat,
+
+    /// Go back and forth through the domain, as if every other repetition of
+    /// the gradient is reversed.
+    Mirror,
+}
+
+/// Wrap `offset` to the range `[0, length)`.
+fn wrap<T>(offset: T, length: T) -> T
+where
+    T: Zero + Arithmetics + PartialOrd + Clone,
+{
+    let offset = offset % length.clone();
+
+    if offset < T::zero() {
+        offset + length
+    } else {
+        offset
+    }
 }
 
 /// An iterator over interpolated colors.
@@ -243,35 +1392,150 @@ where
         }
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.len - self.from_head - self.from_end,
-            Some(self.len - self.from_head - self.from_end),
-        )
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.len - self.from_head - self.from_end,
+            Some(self.len - self.from_head - self.from_end),
+        )
+    }
+}
+
+impl<'a, C, T> DoubleEndedIterator for Take<'a, C, T>
+where
+    C::Scalar: Real + Arithmetics + PartialOrd + Clone,
+    C: Mix + Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.from_head + self.from_end < self.len {
+            if self.len == 1 {
+                self.from_end += 1;
+                Some(self.gradient.get(self.from.clone()))
+            } else {
+                let i = self.from.clone()
+                    + (self.diff.clone() / C::Scalar::from_f64((self.len - 1) as f64))
+                        * C::Scalar::from_f64((self.len - self.from_end - 1) as f64);
+                self.from_end += 1;
+                Some(self.gradient.get(i))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// A gradient that is sampled by perceptual distance, created by
+/// [`Gradient::arc_length`].
+#[derive(Debug)]
+pub struct ArcLength<'a, C, T = Vec<(<C as Mix>::Scalar, C)>>
+where
+    C: Mix + 'a,
+{
+    gradient: &'a Gradient<C, T>,
+    // The positions in the gradient and the distances to them from the start.
+    table: Vec<(C::Scalar, C::Scalar)>,
+}
+
+impl<'a, C, T> ArcLength<'a, C, T>
+where
+    C: Mix + Clone + 'a,
+    C::Scalar: Real + Zero + Arithmetics + IsValidDivisor + PartialOrd + Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Get the color at the normalized arc length `s`, where `0` is the start
+    /// and `1` is the end of the gradient.
+    ///
+    /// Evenly spaced values of `s` give colors with evenly spaced perceptual
+    /// differences. Values outside `0.0..=1.0` give the color at the closest
+    /// end.
+    pub fn sample_by_arclength(&self, s: C::Scalar) -> C {
+        let (_, total) = self.table[self.table.len() - 1].clone();
+        let target = s * total;
+
+        // The first entry that is further along than the target.
+        let next = self
+            .table
+            .iter()
+            .position(|(_, length)| *length > target)
+            .unwrap_or(self.table.len());
+
+        let position = match (next.checked_sub(1), self.table.get(next)) {
+            (Some(previous), Some((end_position, end_length))) => {
+                let (start_position, start_length) = self.table[previous].clone();
+                let segment = end_length.clone() - &start_length;
+                let factor = if segment.is_valid_divisor() {
+                    (target - start_length) / segment
+                } else {
+                    C::Scalar::zero()
+                };
+
+                start_position.clone() + (end_position.clone() - start_position) * factor
+            }
+            (None, _) => self.table[0].0.clone(),
+            (Some(_), None) => self.table[self.table.len() - 1].0.clone(),
+        };
+
+        self.gradient.get(position)
+    }
+
+    /// Get the total perceptual length of the gradient, measured as the
+    /// distance in [`Oklab`].
+    pub fn total_length(&self) -> C::Scalar {
+        self.table[self.table.len() - 1].1.clone()
+    }
+}
+
+/// A lookup table of 8 bit sRGB colors, baked from a gradient with
+/// [`Gradient::bake_lut`].
+///
+/// The colors are evenly spaced, and can be looked up by index or sampled by
+/// position. The table can be serialized and deserialized with the
+/// `serializing` feature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct GradientLut {
+    colors: Vec<Srgb<u8>>,
+}
+
+impl GradientLut {
+    /// Create a lookup table from a list of evenly spaced colors.
+    pub fn new(colors: Vec<Srgb<u8>>) -> Self {
+        GradientLut { colors }
+    }
+
+    /// Get the number of colors in the table.
+    pub fn len(&self) -> usize {
+        self.colors.len()
     }
-}
 
-impl<'a, C, T> DoubleEndedIterator for Take<'a, C, T>
-where
-    C::Scalar: Real + Arithmetics + PartialOrd + Clone,
-    C: Mix + Clone,
-    T: AsRef<[(C::Scalar, C)]>,
-{
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.from_head + self.from_end < self.len {
-            if self.len == 1 {
-                self.from_end += 1;
-                Some(self.gradient.get(self.from.clone()))
-            } else {
-                let i = self.from.clone()
-                    + (self.diff.clone() / C::Scalar::from_f64((self.len - 1) as f64))
-                        * C::Scalar::from_f64((self.len - self.from_end - 1) as f64);
-                self.from_end += 1;
-                Some(self.gradient.get(i))
-            }
-        } else {
-            None
-        }
+    /// Check if the table has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Get the color at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<Srgb<u8>> {
+        self.colors.get(index).copied()
+    }
+
+    /// Get the color closest to `position`, where `0.0` is the first color
+    /// and `1.0` is the last. Positions outside that range give the color at
+    /// the closest end.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the table is empty.
+    pub fn sample(&self, position: f32) -> Srgb<u8> {
+        assert!(!self.colors.is_empty(), "the lookup table is empty");
+
+        let last = self.colors.len() - 1;
+        let index = (clamp(position, 0.0, 1.0) * last as f32).round() as usize;
+        self.colors[index.min(last)]
+    }
+
+    /// Get the colors of the table as a slice.
+    pub fn colors(&self) -> &[Srgb<u8>] {
+        &self.colors
     }
 }
 
@@ -460,6 +1724,90 @@ impl<T> From<::std::ops::RangeFull> for Range<T> {
     }
 }
 
+impl<C, T, U> PartialEq<Gradient<C, U>> for Gradient<C, T>
+where
+    C: Mix + PartialEq,
+    C::Scalar: PartialEq,
+    T: AsRef<[(C::Scalar, C)]>,
+    U: AsRef<[(C::Scalar, C)]>,
+{
+    /// Compare the positions and colors of the control points.
+    fn eq(&self, other: &Gradient<C, U>) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<C, T> AbsDiffEq for Gradient<C, T>
+where
+    C: Mix + AbsDiffEq<Epsilon = <<C as Mix>::Scalar as AbsDiffEq>::Epsilon>,
+    C::Scalar: AbsDiffEq,
+    <C::Scalar as AbsDiffEq>::Epsilon: Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    type Epsilon = <C::Scalar as AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        C::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let (stops, other_stops) = (self.0.as_ref(), other.0.as_ref());
+
+        stops.len() == other_stops.len()
+            && stops.iter().zip(other_stops).all(|((p, c), (op, oc))| {
+                p.abs_diff_eq(op, epsilon.clone()) && c.abs_diff_eq(oc, epsilon.clone())
+            })
+    }
+}
+
+impl<C, T> RelativeEq for Gradient<C, T>
+where
+    C: Mix + RelativeEq<Epsilon = <<C as Mix>::Scalar as AbsDiffEq>::Epsilon>,
+    C::Scalar: RelativeEq,
+    <C::Scalar as AbsDiffEq>::Epsilon: Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        C::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let (stops, other_stops) = (self.0.as_ref(), other.0.as_ref());
+
+        stops.len() == other_stops.len()
+            && stops.iter().zip(other_stops).all(|((p, c), (op, oc))| {
+                p.relative_eq(op, epsilon.clone(), max_relative.clone())
+                    && c.relative_eq(oc, epsilon.clone(), max_relative.clone())
+            })
+    }
+}
+
+impl<C, T> UlpsEq for Gradient<C, T>
+where
+    C: Mix + UlpsEq<Epsilon = <<C as Mix>::Scalar as AbsDiffEq>::Epsilon>,
+    C::Scalar: UlpsEq,
+    <C::Scalar as AbsDiffEq>::Epsilon: Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn default_max_ulps() -> u32 {
+        C::Scalar::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let (stops, other_stops) = (self.0.as_ref(), other.0.as_ref());
+
+        stops.len() == other_stops.len()
+            && stops.iter().zip(other_stops).all(|((p, c), (op, oc))| {
+                p.ulps_eq(op, epsilon.clone(), max_ulps) && c.ulps_eq(oc, epsilon.clone(), max_ulps)
+            })
+    }
+}
+
 impl<T> AbsDiffEq for Range<T>
 where
     T: AbsDiffEq,
@@ -591,8 +1939,102 @@ fn clamp_max<T: PartialOrd>(value: T, max: T) -> T {
 
 #[cfg(test)]
 mod test {
-    use super::{Gradient, Range};
-    use crate::LinSrgb;
+    use super::{oklch_hue_wheel, rainbow, FromStopsStrError, Gradient, Range, WrapMode};
+    use crate::{FromColor, IsWithinBounds, LinSrgb, LinSrgba, Mix, Oklab, Oklch, Srgb};
+
+    #[test]
+    fn color_space_and_linear_alpha() {
+        let start = LinSrgba::new(0.8f64, 0.1, 0.1, 0.2);
+        let end = LinSrgba::new(0.1, 0.2, 0.9, 1.0);
+        let gradient = Gradient::new(vec![start, end]);
+
+        for &i in &[0.0, 0.3, 0.5, 0.9, 1.0] {
+            let color = gradient.get_with_color_space::<Oklab<f64>>(i);
+            assert_relative_eq!(color.alpha, 0.2 + i * 0.8);
+
+            let expected = Oklab::from_color(start.color).mix(Oklab::from_color(end.color), i);
+            assert_relative_eq!(Oklab::from_color(color.color), expected, epsilon = 1e-6);
+        }
+
+        // It's not the same as mixing in linear RGB.
+        let middle = gradient.get_with_color_space::<Oklab<f64>>(0.5);
+        assert!((middle.color.red - gradient.get(0.5).red).abs() > 0.01);
+    }
+
+    #[test]
+    fn bake_lut() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f32, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+        let lut = gradient.bake_lut(5);
+
+        assert_eq!(lut.len(), 5);
+        assert_eq!(lut.get(0), Some(Srgb::new(0, 0, 0)));
+        assert_eq!(lut.get(4), Some(Srgb::new(255, 255, 255)));
+        assert_eq!(lut.get(5), None);
+        assert_eq!(
+            lut.sample(0.5),
+            Srgb::from_linear(gradient.get(0.5)).into_format()
+        );
+        assert_eq!(lut.sample(0.3), lut.get(1).unwrap());
+        assert_eq!(lut.sample(-1.0), lut.get(0).unwrap());
+        assert_eq!(lut.sample(2.0), lut.get(4).unwrap());
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize_lut() {
+        let lut = super::GradientLut::new(vec![Srgb::new(0, 0, 0), Srgb::new(255, 128, 0)]);
+        let serialized = ::serde_json::to_string(&lut).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"colors":[{"red":0,"green":0,"blue":0},{"red":255,"green":128,"blue":0}]}"#
+        );
+
+        let deserialized: super::GradientLut = ::serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, lut);
+        assert_eq!(deserialized.sample(1.0), Srgb::new(255, 128, 0));
+    }
+
+    #[test]
+    fn conic_css_stops() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(1.0f32, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 0.0, 0.0),
+        ]);
+        let stops = gradient.to_conic_css_stops(7);
+        let stops: Vec<&str> = stops.split(", ").collect();
+
+        assert_eq!(stops.len(), 7);
+        assert_eq!(stops[0], "#ff0000 0deg");
+        assert_eq!(stops[2], "#00ff00 120deg");
+        assert_eq!(stops[4], "#0000ff 240deg");
+        assert_eq!(stops[6], "#ff0000 360deg");
+        assert!(stops[1].ends_with(" 60deg"));
+
+        assert_eq!(gradient.to_conic_css_stops(1), "#ff0000 0deg");
+        assert_eq!(gradient.to_conic_css_stops(0), "");
+    }
+
+    #[test]
+    fn stepped_bands() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+        let bands = gradient.stepped(3);
+
+        assert_eq!(bands.len(), 3);
+        assert_relative_eq!(bands[0], LinSrgb::new(1.0 / 6.0, 1.0 / 6.0, 1.0 / 6.0));
+        assert_relative_eq!(bands[1], LinSrgb::new(0.5, 0.5, 0.5));
+        assert_relative_eq!(bands[2], LinSrgb::new(5.0 / 6.0, 5.0 / 6.0, 5.0 / 6.0));
+
+        assert!(gradient.stepped(0).is_empty());
+    }
 
     #[test]
     fn range_clamp() {
@@ -614,6 +2056,41 @@ mod test {
         assert_relative_eq!(range.constrain(&(0.2..0.8).into()), (0.2..0.8).into());
     }
 
+    #[test]
+    fn wrap_modes() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        assert_relative_eq!(g.get_wrapped(1.5, WrapMode::Clamp), g.get(1.0));
+        assert_relative_eq!(g.get_wrapped(-0.5, WrapMode::Clamp), g.get(0.0));
+
+        assert_relative_eq!(g.get_wrapped(1.5, WrapMode::Repeat), g.get(0.5));
+        assert_relative_eq!(g.get_wrapped(1.2, WrapMode::Repeat), g.get(0.2));
+        assert_relative_eq!(g.get_wrapped(-0.2, WrapMode::Repeat), g.get(0.8));
+        assert_relative_eq!(g.get_wrapped(0.7, WrapMode::Repeat), g.get(0.7));
+
+        assert_relative_eq!(g.get_wrapped(1.5, WrapMode::Mirror), g.get(1.0 - 0.5));
+        assert_relative_eq!(g.get_wrapped(1.2, WrapMode::Mirror), g.get(0.8));
+        assert_relative_eq!(g.get_wrapped(-0.2, WrapMode::Mirror), g.get(0.2));
+        assert_relative_eq!(g.get_wrapped(2.2, WrapMode::Mirror), g.get(0.2));
+        assert_relative_eq!(g.get_wrapped(0.7, WrapMode::Mirror), g.get(0.7));
+    }
+
+    #[test]
+    fn wrap_modes_custom_domain() {
+        let g = Gradient::with_domain(vec![
+            (2.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (4.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ]);
+
+        assert_relative_eq!(g.get_wrapped(5.0, WrapMode::Repeat), g.get(3.0));
+        assert_relative_eq!(g.get_wrapped(5.0, WrapMode::Mirror), g.get(3.0));
+        assert_relative_eq!(g.get_wrapped(5.5, WrapMode::Repeat), g.get(3.5));
+        assert_relative_eq!(g.get_wrapped(5.5, WrapMode::Mirror), g.get(2.5));
+    }
+
     #[test]
     fn simple_slice() {
         let g1 = Gradient::new(vec![
@@ -679,4 +2156,445 @@ mod test {
         assert_relative_eq!(v1[0], LinSrgb::new(1.0, 1.0, 0.0));
         assert_relative_eq!(v1[4], LinSrgb::new(0.0, 0.0, 1.0));
     }
+
+    #[test]
+    fn average_black_to_white() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+
+        assert_relative_eq!(g.average(0.0, 1.0), LinSrgb::new(0.5, 0.5, 0.5));
+        assert_relative_eq!(g.average(1.0, 0.0), LinSrgb::new(0.5, 0.5, 0.5));
+        assert_relative_eq!(g.average(0.3, 0.3), g.get(0.3));
+    }
+
+    #[test]
+    fn average_across_stops_and_outside_domain() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 0.0),
+        ]);
+
+        // Red ramps up in [0, 0.5] and stays at 1 in [0.5, 1], while green
+        // ramps up in [0.5, 1].
+        assert_relative_eq!(g.average(0.0, 1.0), LinSrgb::new(0.75, 0.25, 0.0));
+
+        // Half of the range is clamped to black.
+        assert_relative_eq!(g.average(-0.5, 0.5), LinSrgb::new(0.25, 0.0, 0.0));
+    }
+
+    #[test]
+    fn edit_stops() {
+        let mut gradient = Gradient::new(vec![
+            LinSrgb::new(0.0, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+
+        assert_eq!(gradient.insert_stop(0.5, LinSrgb::new(0.0, 0.0, 1.0)), 1);
+        assert_relative_eq!(gradient.get(0.25), LinSrgb::new(0.0, 0.0, 0.5));
+        assert_relative_eq!(gradient.get(0.75), LinSrgb::new(0.5, 0.5, 1.0));
+
+        assert_eq!(gradient.insert_stop(0.5, LinSrgb::new(1.0, 0.0, 0.0)), 2);
+        assert_relative_eq!(gradient.get(0.75), LinSrgb::new(1.0, 0.5, 0.5));
+
+        gradient.set_stop_color(2, LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(gradient.get(0.75), LinSrgb::new(0.5, 1.0, 0.5));
+
+        assert_eq!(gradient.remove_stop(1).0, 0.5);
+        assert_relative_eq!(gradient.get(0.25), LinSrgb::new(0.0, 0.5, 0.0));
+        assert_eq!(gradient.domain(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn enforce_monotonic_lightness() {
+        let colors = vec![
+            LinSrgb::new(0.1, 0.0, 0.0),
+            LinSrgb::new(0.6, 0.6, 0.4),
+            LinSrgb::new(0.1, 0.1, 0.2),
+            LinSrgb::new(0.3, 0.3, 0.3),
+            LinSrgb::new(0.5, 0.9, 0.9),
+        ];
+        let gradient = Gradient::new(colors.iter().map(|&color| Oklab::from_color(color)));
+        let fixed = gradient.enforce_monotonic_lightness();
+        assert_eq!(fixed.domain(), gradient.domain());
+
+        let mut previous = 0.0;
+        for color in fixed.take(200) {
+            assert!(color.l >= previous - 0.00001, "{} < {}", color.l, previous);
+            previous = color.l;
+        }
+
+        // The control points are adjusted in Oklab for other spaces too.
+        let fixed = Gradient::new(colors).enforce_monotonic_lightness();
+        let lightness: Vec<f32> = fixed
+            .0
+            .iter()
+            .map(|&(_, color)| Oklab::from_color(color).l)
+            .collect();
+        assert!(lightness
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1] + 0.00001));
+
+        // Already monotonic gradients stay the same.
+        let sorted = Gradient::new(vec![
+            LinSrgb::new(0.0, 0.0, 0.0),
+            LinSrgb::new(0.2, 0.1, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+        for (original, fixed) in sorted
+            .take(10)
+            .zip(sorted.enforce_monotonic_lightness().take(10))
+        {
+            assert_relative_eq!(original, fixed, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn arc_length_gives_even_steps() {
+        let gradient = Gradient::with_domain(vec![
+            (0.0, LinSrgb::new(0.05f64, 0.05, 0.02)),
+            (0.2, LinSrgb::new(0.9, 0.1, 0.1)),
+            (1.0, LinSrgb::new(0.2, 0.3, 1.0)),
+        ]);
+        let arc_length = gradient.arc_length(1024);
+
+        let samples: Vec<_> = (0..=20)
+            .map(|i| arc_length.sample_by_arclength(i as f64 / 20.0))
+            .collect();
+        let steps: Vec<f64> = samples
+            .windows(2)
+            .map(|pair| super::oklab_distance(pair[0], pair[1]))
+            .collect();
+
+        let expected = arc_length.total_length() / 20.0;
+        for step in steps {
+            assert_relative_eq!(step, expected, max_relative = 0.1);
+        }
+
+        assert_relative_eq!(samples[0], gradient.get(0.0));
+        assert_relative_eq!(samples[20], gradient.get(1.0));
+        assert_relative_eq!(arc_length.sample_by_arclength(2.0), gradient.get(1.0));
+        assert_relative_eq!(arc_length.sample_by_arclength(-1.0), gradient.get(0.0));
+    }
+
+    #[test]
+    fn min_chroma_avoids_gray() {
+        let red = Oklab::from_color(Srgb::new(1.0f64, 0.0, 0.0));
+        let cyan = Oklab::from_color(Srgb::new(0.0f64, 1.0, 1.0));
+        let gradient = Gradient::new(vec![red, cyan]);
+
+        let chroma = |color: Oklab<f64>| color.a.hypot(color.b);
+        assert!(chroma(gradient.get(0.5)) < 0.1);
+
+        for i in 0..=20 {
+            let position = i as f64 / 20.0;
+            let color = gradient.get_with_min_chroma(position, 0.15);
+            assert!(chroma(color) >= 0.15 - 1e-10, "{}: {:?}", position, color);
+        }
+
+        assert_relative_eq!(gradient.get_with_min_chroma(0.0, 0.1), red);
+        assert_relative_eq!(gradient.get_with_min_chroma(1.0, 0.1), cyan);
+
+        // A gray end lowers the limit, so the gray end stays the same.
+        let gray = Oklab::new(0.5, 0.0, 0.0);
+        let gradient = Gradient::new(vec![gray, red]);
+        assert_relative_eq!(gradient.get_with_min_chroma(0.0, 0.1), gray);
+        assert_relative_eq!(gradient.get_with_min_chroma(0.5, 0.1), gradient.get(0.5));
+    }
+
+    #[test]
+    fn gradient_equality() {
+        let stops = vec![
+            (0.0, LinSrgb::new(0.0, 0.0, 0.0)),
+            (0.4, LinSrgb::new(1.0, 0.5, 0.0)),
+            (1.0, LinSrgb::new(1.0, 1.0, 1.0)),
+        ];
+        let gradient = Gradient::with_domain(stops.clone());
+
+        assert_eq!(gradient, Gradient::with_domain(stops.clone()));
+        assert!(gradient == Gradient::with_domain(&stops[..]));
+
+        let mut nudged = stops.clone();
+        nudged[1] = (0.40001, LinSrgb::new(1.0, 0.50001, 0.0));
+        let nudged = Gradient::with_domain(nudged);
+
+        assert_ne!(gradient, nudged);
+        assert_relative_eq!(gradient, nudged, epsilon = 0.0001);
+        assert_relative_ne!(gradient, nudged, epsilon = 0.000001);
+        assert_abs_diff_eq!(gradient, nudged, epsilon = 0.0001);
+
+        let shorter = Gradient::with_domain(stops[..2].to_vec());
+        assert_ne!(gradient, shorter);
+        assert_relative_ne!(gradient, shorter, epsilon = 0.1);
+    }
+
+    #[test]
+    fn two_color_perceptual_has_even_steps() {
+        let red = LinSrgb::new(1.0f64, 0.0, 0.0);
+        let blue = LinSrgb::new(0.0, 0.0, 1.0);
+        let gradient = Gradient::two_color_perceptual(red, blue, 8);
+        assert_eq!(gradient.0.len(), 10);
+
+        // Round the stops to 8 bit sRGB, as when exporting them to CSS.
+        let stops: Vec<Srgb<u8>> = gradient
+            .0
+            .iter()
+            .map(|&(_, color)| Srgb::from_linear(color).into_format())
+            .collect();
+        let steps: Vec<f64> = stops
+            .windows(2)
+            .map(|pair| super::oklab_distance(pair[0].into_format(), pair[1].into_format()))
+            .collect();
+
+        let (min, max) = steps
+            .iter()
+            .fold((f64::INFINITY, 0.0f64), |(min, max), &step| {
+                (min.min(step), max.max(step))
+            });
+        assert!(max / min < 1.1, "uneven steps: {:?}", steps);
+    }
+
+    #[test]
+    fn perceptual_midpoint_fix_red_to_green() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+        ]);
+        let fixed = g.perceptual_midpoint_fix();
+
+        assert_eq!(fixed.0.len(), 3);
+        assert_eq!(fixed.domain(), g.domain());
+        assert_relative_eq!(fixed.get(0.0), g.get(0.0));
+        assert_relative_eq!(fixed.get(1.0), g.get(1.0));
+
+        // The muddy midpoint from mixing the sRGB components directly.
+        let naive = Oklab::from_color(Srgb::new(0.5, 0.5, 0.0));
+        let corrected = Oklab::from_color(fixed.get(0.5));
+        assert!(corrected.l > naive.l);
+    }
+
+    #[test]
+    fn simplify_near_linear() {
+        let g = Gradient::new((0..100).map(|i| {
+            let factor = i as f32 / 99.0;
+            let wobble = if i % 2 == 0 { 0.0001 } else { -0.0001 };
+            LinSrgb::new(factor + wobble, 0.5, 1.0 - factor)
+        }));
+
+        let simplified = g.simplify(10, 0.01);
+        assert_eq!(simplified.0.len(), 2);
+        assert_relative_eq!(simplified.domain().0, 0.0);
+        assert_relative_eq!(simplified.domain().1, 1.0);
+
+        for (original, simplified) in g.take(20).zip(simplified.take(20)) {
+            assert_relative_eq!(original, simplified, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn simplify_keeps_corners() {
+        let g = Gradient::new(vec![
+            Srgb::new(1.0, 0.0, 0.0).into_linear(),
+            Srgb::new(0.8, 0.0, 0.2).into_linear(),
+            Srgb::new(0.0, 1.0, 0.0).into_linear(),
+            Srgb::new(0.0, 0.0, 1.0).into_linear(),
+        ]);
+
+        let simplified = g.simplify(3, 0.0);
+        assert_eq!(simplified.0.len(), 3);
+        assert_relative_eq!(simplified.0[1].0, g.0[2].0);
+
+        assert_eq!(g.simplify(0, 0.0).0.len(), 2);
+        assert_eq!(g.simplify(10, 0.0).0.len(), 4);
+    }
+
+    #[test]
+    fn hue_wheel_in_gamut_and_evenly_spaced() {
+        let wheel = oklch_hue_wheel(0.75f64, 0.2, 24);
+        assert_eq!(wheel.len(), 24);
+
+        for (step, color) in wheel.into_iter().enumerate() {
+            assert!(color.is_within_bounds());
+
+            let oklch = Oklch::from_color(color);
+            assert_relative_eq!(oklch.l, 0.75, epsilon = 0.0001);
+            assert!(oklch.chroma <= 0.2 + 0.0001);
+
+            let expected_hue = step as f64 * 15.0;
+            let hue_difference = (oklch.hue.into_positive_degrees() - expected_hue + 180.0)
+                .rem_euclid(360.0)
+                - 180.0;
+            assert!(
+                hue_difference.abs() < 0.1,
+                "step {}: {}",
+                step,
+                hue_difference
+            );
+        }
+    }
+
+    #[test]
+    fn rainbow_in_gamut_and_evenly_spaced() {
+        let colors = rainbow::<f64>(10);
+        assert_eq!(colors.len(), 10);
+
+        let first = Oklch::from_color(colors[0]);
+        assert!(first.chroma > 0.05);
+
+        for (step, color) in colors.into_iter().enumerate() {
+            assert!(color.is_within_bounds());
+
+            let oklch = Oklch::from_color(color);
+            assert_relative_eq!(oklch.l, 0.75, epsilon = 0.0001);
+            assert_relative_eq!(oklch.chroma, first.chroma, epsilon = 0.0001);
+
+            let expected_hue = step as f64 * 36.0;
+            let hue_difference = (oklch.hue.into_positive_degrees() - expected_hue + 180.0)
+                .rem_euclid(360.0)
+                - 180.0;
+            assert!(hue_difference.abs() < 0.1);
+        }
+
+        assert!(rainbow::<f32>(0).is_empty());
+    }
+
+    #[test]
+    fn texture_bytes() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+        let bytes = gradient.to_texture_bytes::<crate::encoding::Srgb>(5);
+
+        assert_eq!(bytes.len(), 5 * 3);
+        assert_eq!(&bytes[..3], &[0, 0, 255]);
+        assert_eq!(&bytes[12..], &[255, 255, 255]);
+
+        let middle = Srgb::from_linear(LinSrgb::new(0.5f32, 0.5, 1.0)).into_format::<u8>();
+        assert_eq!(&bytes[6..9], &[middle.red, middle.green, middle.blue]);
+
+        assert!(gradient
+            .to_texture_bytes::<crate::encoding::Srgb>(0)
+            .is_empty());
+    }
+
+    #[test]
+    fn map_lightens_each_sample() {
+        use crate::Lighten;
+
+        let gradient = Gradient::with_domain(vec![
+            (0.0, Oklab::new(0.1, 0.1, 0.0)),
+            (0.4, Oklab::new(0.5, 0.0, -0.1)),
+            (1.0, Oklab::new(0.7, -0.1, 0.1)),
+        ]);
+        let lighter = gradient.map(|color| color.lighten_fixed(0.1));
+
+        assert_eq!(lighter.domain(), gradient.domain());
+        for (original, lightened) in gradient.take(11).zip(lighter.take(11)) {
+            assert_relative_eq!(lightened.l, original.l + 0.1, epsilon = 0.00001);
+            assert_relative_eq!(lightened.a, original.a);
+            assert_relative_eq!(lightened.b, original.b);
+        }
+    }
+
+    #[test]
+    fn detect_banding() {
+        let smooth = Gradient::new(vec![
+            Oklab::new(0.0f64, 0.0, 0.0),
+            Oklab::new(1.0, 0.0, 0.0),
+        ]);
+        assert!(smooth.detect_banding(256, 2.3).is_empty());
+        assert!(smooth.detect_banding(1, 0.0).is_empty());
+
+        let hard_stop = Gradient::with_domain(vec![
+            (0.0, Oklab::new(0.3f64, 0.0, -0.1)),
+            (0.3, Oklab::new(0.4, 0.0, -0.1)),
+            (0.3, Oklab::new(0.7, 0.1, 0.1)),
+            (1.0, Oklab::new(0.8, 0.1, 0.1)),
+        ]);
+        let banding = hard_stop.detect_banding(101, 2.3);
+        assert_eq!(banding.len(), 1);
+        assert_relative_eq!(banding[0], 0.3, epsilon = 0.01);
+
+        // Too few samples make even the smooth parts look like steps.
+        assert_eq!(hard_stop.detect_banding(5, 2.3).len(), 4);
+    }
+
+    #[test]
+    fn from_stops_str_three_stops() {
+        let g = Gradient::<LinSrgb<f64>>::from_stops_str(
+            r##"[[0.0, "#0000ff"], [1.0, "#ff0000"], [0.25, "#00ff00"]]"##,
+        )
+        .unwrap();
+
+        assert_eq!(g.domain(), (0.0, 1.0));
+        assert_relative_eq!(g.get(0.0), LinSrgb::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(g.get(0.125), LinSrgb::new(0.0, 0.5, 0.5));
+        assert_relative_eq!(g.get(0.25), LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(g.get(0.625), LinSrgb::new(0.5, 0.5, 0.0));
+        assert_relative_eq!(g.get(1.0), LinSrgb::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn from_stops_str_tuples() {
+        let g = Gradient::<LinSrgb<f64>>::from_stops_str("[(0, 'fff'), (2, '#000'),]").unwrap();
+
+        assert_eq!(g.domain(), (0.0, 2.0));
+        assert_relative_eq!(g.get(0.0), LinSrgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn from_stops_str_errors() {
+        let parse = Gradient::<LinSrgb>::from_stops_str;
+
+        assert!(matches!(parse("[]"), Err(FromStopsStrError::Empty)));
+        assert!(matches!(
+            parse("[[0.0, \"#000\"]"),
+            Err(FromStopsStrError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse("[[0.0, \"#000\"] [1.0, \"#fff\"]]"),
+            Err(FromStopsStrError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse("[[zero, \"#000\"]]"),
+            Err(FromStopsStrError::InvalidPosition)
+        ));
+        assert!(matches!(
+            parse("[[NaN, \"#000\"]]"),
+            Err(FromStopsStrError::InvalidPosition)
+        ));
+        assert!(matches!(
+            parse("[[0.0, \"#00\"]]"),
+            Err(FromStopsStrError::InvalidColor(_))
+        ));
+    }
+
+    #[test]
+    fn convert_interpolates_in_new_space() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(0.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 0.0),
+        ]);
+        let converted = g.convert::<Oklab>();
+
+        assert_relative_eq!(
+            LinSrgb::from_color(converted.get(0.0)),
+            g.get(0.0),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            LinSrgb::from_color(converted.get(1.0)),
+            g.get(1.0),
+            epsilon = 0.0001
+        );
+
+        // The middle point is the Oklab average, not the RGB average.
+        let start = Oklab::from_color(LinSrgb::new(0.0, 0.0, 1.0));
+        let end = Oklab::from_color(LinSrgb::new(1.0, 1.0, 0.0));
+        assert_relative_eq!(converted.get(0.5), (start + end) / 2.0, epsilon = 0.0001);
+        assert!((Oklab::from_color(g.get(0.5)).l - converted.get(0.5).l).abs() > 0.05);
+    }
 }
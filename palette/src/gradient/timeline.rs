@@ -0,0 +1,221 @@
+use crate::{
+    num::{Arithmetics, IsValidDivisor, One, Real},
+    Mix,
+};
+
+/// The shape of the transition between two keyframes in a [`Timeline`].
+///
+/// The easing functions take the linear progress from one keyframe to the
+/// next, from `0` to `1`, and change how fast the color moves along the way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Easing {
+    /// Move at a constant speed.
+    Linear,
+    /// Stay at the previous color until the next keyframe is reached.
+    Hold,
+    /// Start slowly and speed up, following `t²`.
+    EaseIn,
+    /// Start quickly and slow down, following `1 - (1 - t)²`.
+    EaseOut,
+    /// Start and end slowly, following the "smoothstep" curve
+    /// `3t² - 2t³`.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Apply the easing function to the linear progress `t`, where `t` is
+    /// expected to be between `0` and `1`.
+    ///
+    /// ```
+    /// use palette::gradient::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::Hold.apply(0.99), 0.0);
+    /// ```
+    #[must_use]
+    pub fn apply<T>(self, t: T) -> T
+    where
+        T: Real + One + Arithmetics + PartialOrd + Clone,
+    {
+        match self {
+            Easing::Linear => t,
+            Easing::Hold => {
+                if t >= T::one() {
+                    T::one()
+                } else {
+                    T::from_f64(0.0)
+                }
+            }
+            Easing::EaseIn => t.clone() * t,
+            Easing::EaseOut => {
+                let inverse = T::one() - t;
+                T::one() - inverse.clone() * inverse
+            }
+            Easing::EaseInOut => t.clone() * &t * (T::from_f64(3.0) - T::from_f64(2.0) * t),
+        }
+    }
+}
+
+/// A sequence of colors over time, for animating a color.
+///
+/// A timeline is similar to a [`Gradient`](crate::gradient::Gradient), but
+/// each keyframe also has an [`Easing`] that decides how the color moves from
+/// the previous keyframe to it. The color stays the same as the first keyframe
+/// before it, and the same as the last keyframe after it.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{
+///     gradient::{Easing, Timeline},
+///     LinSrgb,
+/// };
+///
+/// let timeline = Timeline::new(0.0, LinSrgb::new(0.0, 0.0, 0.0))
+///     .keyframe(1.0, LinSrgb::new(1.0, 0.0, 0.0), Easing::Linear)
+///     .keyframe(3.0, LinSrgb::new(1.0, 1.0, 0.0), Easing::EaseIn);
+///
+/// assert_relative_eq!(timeline.get(0.5), LinSrgb::new(0.5, 0.0, 0.0));
+/// assert_relative_eq!(timeline.get(2.0), LinSrgb::new(1.0, 0.25, 0.0));
+/// assert_relative_eq!(timeline.get(10.0), LinSrgb::new(1.0, 1.0, 0.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timeline<C>
+where
+    C: Mix,
+{
+    keyframes: Vec<(C::Scalar, C, Easing)>,
+}
+
+impl<C> Timeline<C>
+where
+    C: Mix,
+{
+    /// Create a timeline that starts with `color` at `time`.
+    pub fn new(time: C::Scalar, color: C) -> Self {
+        Timeline {
+            keyframes: vec![(time, color, Easing::Linear)],
+        }
+    }
+
+    /// Add a keyframe with `color` at `time`, which is reached from the
+    /// keyframe before it using `easing`.
+    ///
+    /// The keyframes are kept in time order, so they don't have to be added
+    /// in order. A keyframe that has the same time as an existing one is
+    /// placed after it, which makes the color jump at that time.
+    #[must_use]
+    pub fn keyframe(mut self, time: C::Scalar, color: C, easing: Easing) -> Self
+    where
+        C::Scalar: PartialOrd,
+    {
+        let index = self
+            .keyframes
+            .iter()
+            .position(|(other_time, _, _)| *other_time > time)
+            .unwrap_or(self.keyframes.len());
+        self.keyframes.insert(index, (time, color, easing));
+        self
+    }
+
+    /// Get the color at `time`.
+    pub fn get(&self, time: C::Scalar) -> C
+    where
+        C: Clone,
+        C::Scalar: Real + One + Arithmetics + IsValidDivisor + PartialOrd + Clone,
+    {
+        // The first keyframe that is after `time`, or the end.
+        let next = self
+            .keyframes
+            .iter()
+            .position(|(keyframe_time, _, _)| *keyframe_time > time)
+            .unwrap_or(self.keyframes.len());
+
+        let (start_time, start_color, _) = &self.keyframes[next.saturating_sub(1)];
+        let (end_time, end_color, easing) = match self.keyframes.get(next) {
+            Some(keyframe) if next > 0 => keyframe,
+            _ => return start_color.clone(),
+        };
+
+        let length = end_time.clone() - start_time;
+        let progress = if length.is_valid_divisor() {
+            (time - start_time) / length
+        } else {
+            C::Scalar::one()
+        };
+
+        start_color
+            .clone()
+            .mix(end_color.clone(), easing.apply(progress))
+    }
+
+    /// Get the times of the first and the last keyframes.
+    pub fn domain(&self) -> (C::Scalar, C::Scalar)
+    where
+        C::Scalar: Clone,
+    {
+        let (start, _, _) = &self.keyframes[0];
+        let (end, _, _) = &self.keyframes[self.keyframes.len() - 1];
+        (start.clone(), end.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Easing, Timeline};
+    use crate::LinSrgb;
+
+    #[test]
+    fn keyframes_are_exact() {
+        let colors = [
+            LinSrgb::new(0.0, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.2, 0.8, 0.4),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ];
+        let timeline = Timeline::new(0.0, colors[0])
+            .keyframe(2.0, colors[2], Easing::EaseOut)
+            .keyframe(1.0, colors[1], Easing::EaseIn)
+            .keyframe(4.0, colors[3], Easing::Hold);
+
+        assert_eq!(timeline.domain(), (0.0, 4.0));
+        for (time, color) in [0.0, 1.0, 2.0, 4.0].iter().zip(&colors) {
+            assert_relative_eq!(timeline.get(*time), *color);
+        }
+
+        assert_relative_eq!(timeline.get(-1.0), colors[0]);
+        assert_relative_eq!(timeline.get(3.9), colors[2]);
+        assert_relative_eq!(timeline.get(5.0), colors[3]);
+    }
+
+    #[test]
+    fn jump_at_same_time() {
+        let timeline = Timeline::new(0.0, LinSrgb::new(0.0, 0.0, 0.0))
+            .keyframe(1.0, LinSrgb::new(1.0, 1.0, 1.0), Easing::Linear)
+            .keyframe(1.0, LinSrgb::new(0.0, 0.0, 1.0), Easing::Linear)
+            .keyframe(2.0, LinSrgb::new(0.0, 0.0, 0.0), Easing::Linear);
+
+        assert_relative_eq!(timeline.get(0.5), LinSrgb::new(0.5, 0.5, 0.5));
+        assert_relative_eq!(timeline.get(1.0), LinSrgb::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(timeline.get(1.5), LinSrgb::new(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn easing_end_points() {
+        let easings = [
+            Easing::Linear,
+            Easing::Hold,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ];
+
+        for easing in &easings {
+            assert_relative_eq!(easing.apply(0.0f64), 0.0);
+            assert_relative_eq!(easing.apply(1.0f64), 1.0);
+        }
+
+        assert_relative_eq!(Easing::EaseOut.apply(0.5f64), 0.75);
+        assert_relative_eq!(Easing::EaseInOut.apply(0.5f64), 0.5);
+    }
+}
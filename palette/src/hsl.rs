@@ -2,6 +2,7 @@ use core::{
     any::TypeId,
     marker::PhantomData,
     ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
 };
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -21,6 +22,7 @@ use crate::{
     angle::{FromAngle, RealAngle, SignedAngle},
     clamp, clamp_assign, contrast_ratio,
     convert::FromColorUnclamped,
+    css::{self, ParseCssError},
     encoding::Srgb,
     num::{Arithmetics, IsValidDivisor, MinMax, One, Real, Zero},
     rgb::{Rgb, RgbSpace, RgbStandard},
@@ -200,6 +202,52 @@ where
     }
 }
 
+impl<S, T> Hsl<S, T>
+where
+    T: Stimulus + PartialOrd,
+{
+    /// Check if the color is achromatic, meaning that it's a shade of gray and
+    /// its hue has no effect.
+    ///
+    /// This is the case when either `saturation` is `0` or `lightness` is at
+    /// its minimum or maximum.
+    pub fn is_achromatic(&self) -> bool {
+        self.saturation <= T::zero()
+            || self.lightness <= T::zero()
+            || self.lightness >= T::max_intensity()
+    }
+
+    /// Replace the hue with `hue` if the color is achromatic.
+    ///
+    /// Converting a shade of gray to HSL gives a hue of `0`, since any hue
+    /// would result in the same color. This makes the hue jump when a color
+    /// goes through gray and back, which is a problem for color pickers and
+    /// similar user interfaces. Applying the previous hue after converting
+    /// keeps it "sticky", without changing the color.
+    ///
+    /// ```
+    /// use palette::{FromColor, Hsl, Saturate, Srgb};
+    ///
+    /// let picked = Hsl::new_srgb(120.0, 0.0, 0.5);
+    ///
+    /// // The hue is lost when the gray color goes through RGB.
+    /// let gray = Hsl::from_color(Srgb::from_color(picked));
+    /// assert_eq!(gray.hue, 0.0);
+    ///
+    /// let gray = gray.with_preserved_hue(picked.hue);
+    /// assert_eq!(gray.hue, 120.0);
+    /// assert_eq!(gray.saturate_fixed(1.0).hue, 120.0);
+    /// ```
+    #[must_use]
+    pub fn with_preserved_hue<H: Into<RgbHue<T>>>(mut self, hue: H) -> Self {
+        if self.is_achromatic() {
+            self.hue = hue.into();
+        }
+
+        self
+    }
+}
+
 ///<span id="Hsla"></span>[`Hsla`](crate::Hsla) implementations.
 impl<T, A> Alpha<Hsl<Srgb, T>, A> {
     /// Create an sRGB HSL color with transparency. This method can be used
@@ -405,6 +453,34 @@ impl<S, T, A> From<Alpha<Hsl<S, T>, A>> for (RgbHue<T>, T, T, A) {
     }
 }
 
+impl<T> FromStr for Hsl<Srgb, T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `hsl()` color, such as `hsl(120deg 50% 25%)`. See the
+    /// [`css`](crate::css) module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Hsl<Srgb, T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["hsl", "hsla"];
+
+    fn from_components([hue, saturation, lightness]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Hsl::new_srgb(
+            T::from_f64(css::parse_hue(hue)?),
+            T::from_f64(css::parse_number(saturation, 100.0)? / 100.0),
+            T::from_f64(css::parse_number(lightness, 100.0)? / 100.0),
+        ))
+    }
+}
+
 impl<S, T> IsWithinBounds for Hsl<S, T>
 where
     T: Stimulus + PartialOrd,
@@ -659,7 +735,42 @@ unsafe impl<S: 'static, T> bytemuck::Pod for Hsl<S, T> where T: bytemuck::Pod {}
 #[cfg(test)]
 mod test {
     use super::Hsl;
-    use crate::{FromColor, Hsv, Srgb};
+    use crate::{Desaturate, FromColor, Hsv, Saturate, Srgb};
+
+    #[test]
+    fn from_str() {
+        let hsl: Hsl = "hsl(120deg 50% 25%)".parse().unwrap();
+        assert_relative_eq!(hsl, Hsl::new_srgb(120.0, 0.5, 0.25));
+
+        let legacy: Hsl = "hsl(120, 50%, 25%)".parse().unwrap();
+        assert_relative_eq!(legacy, hsl);
+
+        assert!("hwb(120 50% 25%)".parse::<Hsl>().is_err());
+    }
+
+    #[test]
+    fn preserved_hue_through_gray() {
+        let picked = Hsl::new_srgb(200.0, 0.8, 0.6);
+        let gray = picked.desaturate_fixed(1.0);
+        assert!(gray.is_achromatic());
+
+        let converted = Hsl::from_color(Srgb::from_color(gray));
+        assert_eq!(converted.hue, 0.0);
+
+        let resaturated = converted.with_preserved_hue(picked.hue).saturate_fixed(0.8);
+        assert_relative_eq!(resaturated, picked);
+    }
+
+    #[test]
+    fn achromatic_lightness() {
+        assert!(Hsl::new_srgb(30.0, 0.5, 0.0).is_achromatic());
+        assert!(Hsl::new_srgb(30.0, 0.5, 1.0).is_achromatic());
+        assert!(!Hsl::new_srgb(30.0, 0.5, 0.5).is_achromatic());
+        assert_eq!(
+            Hsl::new_srgb(30.0, 0.5, 0.5).with_preserved_hue(200.0).hue,
+            30.0
+        );
+    }
 
     #[test]
     fn red() {
@@ -202,6 +202,49 @@ where
     }
 }
 
+impl<S, T> Hsv<S, T>
+where
+    T: Stimulus + PartialOrd,
+{
+    /// Check if the color is achromatic, meaning that it's a shade of gray and
+    /// its hue has no effect.
+    ///
+    /// This is the case when either `saturation` or `value` is `0`.
+    pub fn is_achromatic(&self) -> bool {
+        self.saturation <= T::zero() || self.value <= T::zero()
+    }
+
+    /// Replace the hue with `hue` if the color is achromatic.
+    ///
+    /// Converting a shade of gray to HSV gives a hue of `0`, since any hue
+    /// would result in the same color. This makes the hue jump when a color
+    /// goes through gray and back, which is a problem for color pickers and
+    /// similar user interfaces. Applying the previous hue after converting
+    /// keeps it "sticky", without changing the color.
+    ///
+    /// ```
+    /// use palette::{FromColor, Hsv, Saturate, Srgb};
+    ///
+    /// let picked = Hsv::new_srgb(120.0, 0.0, 0.5);
+    ///
+    /// // The hue is lost when the gray color goes through RGB.
+    /// let gray = Hsv::from_color(Srgb::from_color(picked));
+    /// assert_eq!(gray.hue, 0.0);
+    ///
+    /// let gray = gray.with_preserved_hue(picked.hue);
+    /// assert_eq!(gray.hue, 120.0);
+    /// assert_eq!(gray.saturate_fixed(1.0).hue, 120.0);
+    /// ```
+    #[must_use]
+    pub fn with_preserved_hue<H: Into<RgbHue<T>>>(mut self, hue: H) -> Self {
+        if self.is_achromatic() {
+            self.hue = hue.into();
+        }
+
+        self
+    }
+}
+
 ///<span id="Hsva"></span>[`Hsva`](crate::Hsva) implementations.
 impl<T, A> Alpha<Hsv<Srgb, T>, A> {
     /// Create an sRGB HSV color with transparency. This method can be used
@@ -664,7 +707,28 @@ unsafe impl<S: 'static, T> bytemuck::Pod for Hsv<S, T> where T: bytemuck::Pod {}
 #[cfg(test)]
 mod test {
     use super::Hsv;
-    use crate::{FromColor, Hsl, Srgb};
+    use crate::{Desaturate, FromColor, Hsl, Saturate, Srgb};
+
+    #[test]
+    fn preserved_hue_through_gray() {
+        let picked = Hsv::new_srgb(200.0, 0.8, 0.6);
+        let gray = picked.desaturate_fixed(1.0);
+        assert!(gray.is_achromatic());
+
+        let converted = Hsv::from_color(Srgb::from_color(gray));
+        assert_eq!(converted.hue, 0.0);
+
+        let resaturated = converted.with_preserved_hue(picked.hue).saturate_fixed(0.8);
+        assert_relative_eq!(resaturated, picked);
+    }
+
+    #[test]
+    fn preserved_hue_ignored_for_chromatic_colors() {
+        let color = Hsv::new_srgb(30.0, 0.5, 0.5);
+        assert!(!color.is_achromatic());
+        assert_eq!(color.with_preserved_hue(200.0), color);
+        assert!(Hsv::new_srgb(30.0, 0.5, 0.0).is_achromatic());
+    }
 
     #[test]
     fn red() {
@@ -19,9 +19,66 @@ use crate::angle::FullRotation;
 
 use crate::{
     angle::{AngleEq, FromAngle, HalfRotation, RealAngle, SignedAngle, UnsignedAngle},
-    num::Zero,
+    num::{IsFinite, Zero},
 };
 
+/// A common interface for the hue types.
+///
+/// Each hue type is tied to its own family of color spaces, so they can't be
+/// mixed up by accident. This trait makes it possible to treat any of them as
+/// a plain angle in degrees, which is useful for generic code and for
+/// explicitly moving a hue value from one type to another.
+///
+/// Keep in mind that the same angle doesn't represent the same perceived hue
+/// in all color spaces. A red at `0°` in RGB is not the same as `0°` in
+/// CIE L\*a\*b\*.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{Hue, LabHue, OklabHue};
+///
+/// let lab_hue = LabHue::new(120.0f32);
+/// let oklab_hue: OklabHue = lab_hue.into_hue();
+///
+/// assert_relative_eq!(Hue::into_degrees(oklab_hue), 120.0);
+/// ```
+pub trait Hue: Sized {
+    /// The angle type that represents the degrees.
+    type Angle;
+
+    /// Create a new hue from an angle in degrees.
+    #[must_use]
+    fn from_degrees(degrees: Self::Angle) -> Self;
+
+    /// Get the hue as degrees, in the range `(-180, 180]`.
+    #[must_use]
+    fn into_degrees(self) -> Self::Angle;
+
+    /// Convert the hue into another hue type, using the same angle.
+    #[must_use]
+    #[inline]
+    fn into_hue<H>(self) -> H
+    where
+        H: Hue<Angle = Self::Angle>,
+    {
+        H::from_degrees(self.into_degrees())
+    }
+}
+
+/// The error type returned when trying to create a hue from an infinite or NaN
+/// angle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteHueError;
+
+impl core::fmt::Display for NonFiniteHueError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("hue angle is not finite")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonFiniteHueError {}
+
 macro_rules! make_hues {
     ($($(#[$doc:meta])+ struct $name:ident;)+) => ($(
         $(#[$doc])+
@@ -72,6 +129,22 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: IsFinite> $name<T> {
+            /// Create a new hue, like [`new`](Self::new), but return an error
+            /// if the angle is infinite or NaN.
+            ///
+            /// Finite angles are accepted as they are, and normalized in the
+            /// same way as for `new` when the hue is converted to a number.
+            #[inline]
+            pub fn try_new(angle: T) -> Result<Self, NonFiniteHueError> {
+                if angle.is_finite() {
+                    Ok(Self(angle))
+                } else {
+                    Err(NonFiniteHueError)
+                }
+            }
+        }
+
         impl<T: RealAngle> $name<T> {
             /// Create a new hue from degrees. This is an alias for `new`.
             #[inline]
@@ -126,6 +199,20 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: RealAngle + SignedAngle> Hue for $name<T> {
+            type Angle = T;
+
+            #[inline]
+            fn from_degrees(degrees: T) -> Self {
+                Self::from_degrees(degrees)
+            }
+
+            #[inline]
+            fn into_degrees(self) -> T {
+                self.into_degrees()
+            }
+        }
+
         impl<T> From<T> for $name<T> {
             #[inline]
             fn from(degrees: T) -> $name<T> {
@@ -520,7 +607,7 @@ impl_uniform!(UniformOklabHue, OklabHue);
 mod test {
     use crate::{
         angle::{SignedAngle, UnsignedAngle},
-        RgbHue,
+        Hue, LabHue, LuvHue, NonFiniteHueError, OklabHue, RgbHue,
     };
 
     #[test]
@@ -619,6 +706,46 @@ mod test {
         }
     }
 
+    #[test]
+    fn hue_round_trip() {
+        fn round_trip<H: Hue<Angle = f32>>() {
+            let hue = H::from_degrees(120.0);
+
+            let lab: LabHue = hue.into_hue();
+            let luv: LuvHue = lab.into_hue();
+            let rgb: RgbHue = luv.into_hue();
+            let oklab: OklabHue = rgb.into_hue();
+            let hue: H = oklab.into_hue();
+
+            assert_relative_eq!(hue.into_degrees(), 120.0);
+        }
+
+        round_trip::<LabHue>();
+        round_trip::<LuvHue>();
+        round_trip::<RgbHue>();
+        round_trip::<OklabHue>();
+    }
+
+    #[test]
+    fn hue_into_degrees_is_normalized() {
+        assert_relative_eq!(Hue::into_degrees(LabHue::new(480.0f32)), 120.0);
+        assert_relative_eq!(Hue::into_degrees(OklabHue::new(-240.0f32)), 120.0);
+    }
+
+    #[test]
+    fn try_new_rejects_non_finite() {
+        assert_eq!(RgbHue::try_new(f32::NAN), Err(NonFiniteHueError));
+        assert_eq!(RgbHue::try_new(f32::INFINITY), Err(NonFiniteHueError));
+        assert_eq!(RgbHue::try_new(f64::NEG_INFINITY), Err(NonFiniteHueError));
+    }
+
+    #[test]
+    fn try_new_normalizes_finite() {
+        let hue = RgbHue::try_new(400.0f32).unwrap();
+        assert_relative_eq!(hue.into_degrees(), 40.0);
+        assert_relative_eq!(hue.into_positive_degrees(), 40.0);
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
@@ -2,6 +2,7 @@ use core::{
     any::TypeId,
     marker::PhantomData,
     ops::{Add, AddAssign, DivAssign, Sub, SubAssign},
+    str::FromStr,
 };
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -18,6 +19,7 @@ use crate::{
     angle::{FromAngle, RealAngle, SignedAngle},
     clamp, clamp_min, clamp_min_assign, contrast_ratio,
     convert::FromColorUnclamped,
+    css::{self, ParseCssError},
     encoding::Srgb,
     num::{Arithmetics, MinMax, One, Real, Zero},
     rgb::{RgbSpace, RgbStandard},
@@ -350,6 +352,34 @@ impl<S, T, A> From<Alpha<Hwb<S, T>, A>> for (RgbHue<T>, T, T, A) {
     }
 }
 
+impl<T> FromStr for Hwb<Srgb, T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `hwb()` color, such as `hwb(120deg 10% 20%)`. See the
+    /// [`css`](crate::css) module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Hwb<Srgb, T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["hwb"];
+
+    fn from_components([hue, whiteness, blackness]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Hwb::new_srgb(
+            T::from_f64(css::parse_hue(hue)?),
+            T::from_f64(css::parse_number(whiteness, 100.0)? / 100.0),
+            T::from_f64(css::parse_number(blackness, 100.0)? / 100.0),
+        ))
+    }
+}
+
 impl<S, T> IsWithinBounds for Hwb<S, T>
 where
     T: Stimulus + PartialOrd + Add<Output = T> + Clone,
@@ -765,6 +795,14 @@ mod test {
     use super::Hwb;
     use crate::{Clamp, FromColor, Srgb};
 
+    #[test]
+    fn from_str() {
+        let hwb: Hwb = "hwb(200 10% 20)".parse().unwrap();
+        assert_relative_eq!(hwb, Hwb::new_srgb(200.0, 0.1, 0.2));
+
+        assert!("hsl(200 10% 20%)".parse::<Hwb>().is_err());
+    }
+
     #[test]
     fn red() {
         let a = Hwb::from_color(Srgb::new(1.0, 0.0, 0.0));
@@ -1,6 +1,7 @@
 use core::{
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
 };
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -19,8 +20,9 @@ use crate::{
     color_difference::{get_ciede_difference, ColorDifference, LabColorDiff},
     contrast_ratio,
     convert::FromColorUnclamped,
+    css::{self, ParseCssError},
     num::{Abs, Arithmetics, Cbrt, Exp, MinMax, One, Powi, Real, Sqrt, Trigonometry, Zero},
-    white_point::{WhitePoint, D65},
+    white_point::{WhitePoint, D50, D65},
     Alpha, Clamp, ClampAssign, ComponentWise, FromColor, GetHue, IsWithinBounds, LabHue, Lch,
     Lighten, LightenAssign, Mix, MixAssign, RelativeContrast, Xyz,
 };
@@ -105,6 +107,31 @@ impl<Wp, T> Lab<Wp, T> {
     }
 }
 
+impl Lab<D65, f32> {
+    /// Convert an 8 bit sRGB color to CIE L\*a\*b\*.
+    ///
+    /// This is a faster alternative to `Lab::from_color(color.into_format())`
+    /// that uses [`Rgb::into_linear_f32`](crate::rgb::Rgb::into_linear_f32)
+    /// to skip the expensive part of decoding the sRGB components.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{FromColor, Lab, Srgb};
+    ///
+    /// let color = Srgb::new(23u8, 198, 76);
+    ///
+    /// assert_relative_eq!(
+    ///     Lab::from_srgb_u8(color),
+    ///     Lab::from_color(color.into_format::<f32>()),
+    ///     epsilon = 0.001
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_srgb_u8(color: crate::Srgb<u8>) -> Self {
+        Self::from_color_unclamped(color.into_linear_f32())
+    }
+}
+
 impl<Wp, T> Lab<Wp, T>
 where
     T: Zero + Real,
@@ -140,6 +167,71 @@ where
     }
 }
 
+impl<Wp, T> Lab<Wp, T>
+where
+    T: Zero + Real + Arithmetics,
+{
+    /// Map the position `t` of a lightness slider, from `0` to `1`, to an L\*
+    /// lightness.
+    ///
+    /// L\* is already perceptually even, so the position is `L* / 100`. This
+    /// is the same scale as
+    /// [`Oklab::slider_to_lightness`](crate::Oklab::slider_to_lightness) uses,
+    /// so sliders for both spaces line up.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Lab;
+    ///
+    /// let l = Lab::<palette::white_point::D65, f32>::slider_to_lightness(0.25);
+    /// assert_relative_eq!(l, 25.0);
+    /// ```
+    pub fn slider_to_lightness(t: T) -> T {
+        t * Self::max_l()
+    }
+
+    /// Map an L\* lightness to the position of a lightness slider, from `0`
+    /// to `1`. This is the inverse of
+    /// [`slider_to_lightness`](Lab::slider_to_lightness).
+    pub fn lightness_to_slider(l: T) -> T {
+        l / Self::max_l()
+    }
+}
+
+impl<Wp, T> Lab<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real + Arithmetics + PartialOrd + Clone,
+    Xyz<Wp, T>: FromColorUnclamped<Self>,
+    Self: FromColorUnclamped<Xyz<Wp, T>>,
+{
+    /// Mix `self` and `other` as if they were two lights shining on the same
+    /// spot.
+    ///
+    /// The colors are added together in [`Xyz`], which is linear in the
+    /// amount of light, so mixing red and green light gives yellow. This is
+    /// different from [`Mix`], which moves along a straight line in
+    /// Lab and gives a color between the two. The sum is scaled down to
+    /// the luminance of the white point if it's brighter, while keeping its
+    /// chromaticity.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Lab, FromColor, LinSrgb};
+    ///
+    /// let red = Lab::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+    /// let green = Lab::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+    /// let yellow = LinSrgb::from_color(red.mix_additive(green));
+    ///
+    /// assert_relative_eq!(yellow, LinSrgb::new(1.0, 1.0, 0.0), epsilon = 0.001);
+    /// ```
+    #[must_use]
+    pub fn mix_additive(self, other: Self) -> Self {
+        let sum = Xyz::from_color_unclamped(self).add_lights(Xyz::from_color_unclamped(other));
+        Self::from_color_unclamped(sum)
+    }
+}
+
 ///<span id="Laba"></span>[`Laba`](crate::Laba) implementations.
 impl<Wp, T, A> Alpha<Lab<Wp, T>, A> {
     /// Create a CIE L\*a\*b\* with transparency.
@@ -248,6 +340,35 @@ impl<Wp, T, A> From<Alpha<Lab<Wp, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl<T> FromStr for Lab<D50, T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `lab()` color, such as `lab(50% 40 -20)`. CSS defines
+    /// `lab()` relative to the D50 white point. See the [`css`](crate::css)
+    /// module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Lab<D50, T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["lab"];
+
+    fn from_components([l, a, b]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Lab::new(
+            T::from_f64(css::parse_number(l, 100.0)?),
+            T::from_f64(css::parse_number(a, 125.0)?),
+            T::from_f64(css::parse_number(b, 125.0)?),
+        ))
+    }
+}
+
 impl<Wp, T> IsWithinBounds for Lab<Wp, T>
 where
     T: Zero + Real + PartialOrd,
@@ -482,8 +603,72 @@ unsafe impl<Wp: 'static, T> bytemuck::Pod for Lab<Wp, T> where T: bytemuck::Pod
 #[cfg(test)]
 mod test {
     use super::Lab;
-    use crate::white_point::D65;
-    use crate::{FromColor, LinSrgb};
+    use crate::white_point::{D50, D65};
+    use crate::{FromColor, FromGray, LinSrgb, Oklab, Srgb};
+
+    #[test]
+    fn lightness_slider() {
+        type Lab = super::Lab<D65, f64>;
+
+        assert_relative_eq!(Lab::slider_to_lightness(0.0), Lab::min_l());
+        assert_relative_eq!(Lab::slider_to_lightness(1.0), Lab::max_l());
+        assert_relative_eq!(Lab::lightness_to_slider(Lab::min_l()), 0.0);
+        assert_relative_eq!(Lab::lightness_to_slider(Lab::max_l()), 1.0);
+
+        let mut previous = -1.0;
+        for i in 0..=100 {
+            let t = f64::from(i) / 100.0;
+            let l = Lab::slider_to_lightness(t);
+            assert!(l > previous);
+            assert_relative_eq!(Lab::lightness_to_slider(l), t, epsilon = 1e-12);
+            previous = l;
+
+            // The Oklab slider approximately follows the same scale.
+            let gray = Oklab::from_color(Lab::new(l, 0.0, 0.0));
+            assert_relative_eq!(Oklab::lightness_to_slider(gray.l), t, epsilon = 0.03);
+        }
+    }
+
+    #[test]
+    fn gray() {
+        assert_eq!(Srgb::gray(0.5), Srgb::new(0.5, 0.5, 0.5));
+
+        let lab = Lab::<D65, f64>::grey(0.5);
+        assert_relative_eq!(lab.a, 0.0, epsilon = 0.0001);
+        assert_relative_eq!(lab.b, 0.0, epsilon = 0.0001);
+        assert_relative_eq!(lab.l, 53.389, epsilon = 0.001);
+
+        assert_relative_eq!(Lab::<D65, f64>::gray(0.0), Lab::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(
+            Lab::<D65, f64>::gray(1.0),
+            Lab::new(100.0, 0.0, 0.0),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let lab: Lab<D50> = "lab(50% 40 -20%)".parse().unwrap();
+        assert_relative_eq!(lab, Lab::new(50.0, 40.0, -25.0));
+
+        assert!("lch(50 40 -20)".parse::<Lab<D50>>().is_err());
+        assert!("lab(50 forty -20)".parse::<Lab<D50>>().is_err());
+    }
+
+    #[test]
+    fn from_srgb_u8_parity() {
+        for value in 0..=255u8 {
+            let colors = [
+                Srgb::new(value, value, value),
+                Srgb::new(value, 255 - value, value / 2),
+            ];
+
+            for &color in colors.iter() {
+                let expected = Lab::from_color(color.into_format::<f32>());
+                assert_relative_eq!(Lab::from_srgb_u8(color), expected, epsilon = 0.001);
+            }
+        }
+    }
 
     #[test]
     fn red() {
@@ -1,6 +1,7 @@
 use core::{
     marker::PhantomData,
     ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
 };
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -19,8 +20,9 @@ use crate::{
     color_difference::{get_ciede_difference, ColorDifference, LabColorDiff},
     contrast_ratio,
     convert::FromColorUnclamped,
+    css::{self, ParseCssError},
     num::{Abs, Arithmetics, Exp, Hypot, MinMax, One, Powi, Real, Sqrt, Trigonometry, Zero},
-    white_point::D65,
+    white_point::{D50, D65},
     Alpha, Clamp, ClampAssign, FromColor, GetHue, IsWithinBounds, Lab, LabHue, Lighten,
     LightenAssign, Mix, MixAssign, RelativeContrast, Saturate, SaturateAssign, SetHue, ShiftHue,
     ShiftHueAssign, WithHue, Xyz,
@@ -219,6 +221,35 @@ impl<Wp, T, A> From<Alpha<Lch<Wp, T>, A>> for (T, T, LabHue<T>, A) {
     }
 }
 
+impl<T> FromStr for Lch<D50, T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `lch()` color, such as `lch(50% 30 120)`. CSS defines
+    /// `lch()` relative to the D50 white point. See the [`css`](crate::css)
+    /// module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Lch<D50, T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["lch"];
+
+    fn from_components([l, chroma, hue]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Lch::new(
+            T::from_f64(css::parse_number(l, 100.0)?),
+            T::from_f64(css::parse_number(chroma, 150.0)?),
+            T::from_f64(css::parse_hue(hue)?),
+        ))
+    }
+}
+
 impl<Wp, T> IsWithinBounds for Lch<Wp, T>
 where
     T: Zero + Real + PartialOrd,
@@ -480,9 +511,22 @@ unsafe impl<Wp: 'static, T> bytemuck::Pod for Lch<Wp, T> where T: bytemuck::Pod
 
 #[cfg(test)]
 mod test {
-    use crate::white_point::D65;
+    use crate::white_point::{D50, D65};
     use crate::Lch;
 
+    #[test]
+    fn from_str() {
+        let lch: Lch<D50> = "lch(50 30 120)".parse().unwrap();
+        assert_relative_eq!(lch, Lch::new(50.0, 30.0, 120.0));
+
+        let lch: Lch<D50> = "lch(50% 20% 0.25turn)".parse().unwrap();
+        assert_relative_eq!(lch, Lch::new(50.0, 30.0, 90.0));
+
+        assert!("rgb(255 0 0)".parse::<Lch<D50>>().is_err());
+        assert!("lab(50 30 120)".parse::<Lch<D50>>().is_err());
+        assert!("lch(50 30)".parse::<Lch<D50>>().is_err());
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
@@ -60,7 +60,8 @@ pub struct Lchuv<Wp = D65, T = f32> {
     pub chroma: T,
 
     /// The hue of the color, in degrees. Decides if it's red, blue, purple,
-    /// etc.
+    /// etc. It's set to 0.0 when converting from an achromatic color, where
+    /// `u` and `v` are both 0.0 and the hue is undefined.
     #[palette(unsafe_same_layout_as = "T")]
     pub hue: LuvHue<T>,
 
@@ -472,7 +473,18 @@ unsafe impl<Wp: 'static, T> bytemuck::Pod for Lchuv<Wp, T> where T: bytemuck::Po
 #[cfg(test)]
 mod test {
     use crate::white_point::D65;
-    use crate::Lchuv;
+    use crate::{FromColor, Lchuv, Luv};
+
+    #[test]
+    fn achromatic_hue() {
+        for &l in &[0.0, 50.0, 100.0] {
+            let lchuv = Lchuv::from_color(Luv::<D65, f64>::new(l, 0.0, 0.0));
+
+            assert_eq!(lchuv.l, l);
+            assert_eq!(lchuv.chroma, 0.0);
+            assert_eq!(lchuv.hue.into_raw_degrees(), 0.0);
+        }
+    }
 
     #[test]
     fn ranges() {
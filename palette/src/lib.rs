@@ -226,6 +226,8 @@ use core::ops::Neg;
 
 use luma::Luma;
 
+#[cfg(feature = "std")]
+pub use alpha::{add_alpha_vec, strip_alpha_vec};
 pub use alpha::{Alpha, WithAlpha};
 pub use blend::Blend;
 #[cfg(feature = "std")]
@@ -248,10 +250,40 @@ pub use yxy::{Yxy, Yxya};
 
 pub use color_difference::ColorDifference;
 pub use convert::{FromColor, FromColorMut, FromColorMutGuard, IntoColor, IntoColorMut};
-pub use hues::{LabHue, LuvHue, OklabHue, RgbHue};
+pub use hues::{Hue, LabHue, LuvHue, NonFiniteHueError, OklabHue, RgbHue};
 pub use matrix::Mat3;
 pub use relative_contrast::{contrast_ratio, RelativeContrast};
 
+/// Create an 8 bit sRGB color from a string literal, checked at compile time.
+///
+/// The literal can be a hex code, with 3, 4, 6 or 8 digits, or a CSS style
+/// `rgb(red, green, blue)` or `rgba(red, green, blue, alpha)` expression with
+/// components in `[0, 255]`. It becomes an [`Srgb<u8>`](Srgb) if there's no
+/// alpha component, and an [`Srgba<u8>`](Srgba) otherwise. The result can
+/// also be used in constants.
+///
+/// ```
+/// use palette::{color, Srgb, Srgba};
+///
+/// const ORANGE: Srgb<u8> = color!("#ff8000");
+/// assert_eq!(ORANGE, Srgb::new(255, 128, 0));
+///
+/// assert_eq!(color!("#f80"), Srgb::new(255, 136, 0));
+/// assert_eq!(color!("#ff800080"), Srgba::new(255, 128, 0, 128));
+/// assert_eq!(color!("rgb(30, 60, 90)"), Srgb::new(30, 60, 90));
+/// ```
+///
+/// Invalid colors are reported as compile errors:
+///
+/// ```compile_fail
+/// let color = palette::color!("#ff80zz");
+/// ```
+///
+/// ```compile_fail
+/// let color = palette::color!("rgb(300, 0, 0)");
+/// ```
+pub use palette_derive::color;
+
 //Helper macro for checking ranges and clamping.
 #[cfg(test)]
 macro_rules! assert_ranges {
@@ -427,7 +459,12 @@ pub mod cast;
 pub mod chromatic_adaptation;
 mod color_difference;
 pub mod convert;
+pub mod css;
+#[cfg(feature = "std")]
+pub mod description;
 pub mod encoding;
+#[cfg(feature = "std")]
+pub mod gamut;
 mod hsl;
 mod hsluv;
 mod hsv;
@@ -442,8 +479,13 @@ mod luv_bounds;
 pub mod num;
 mod oklab;
 mod oklch;
+pub mod prelude;
 mod relative_contrast;
 pub mod rgb;
+#[cfg(feature = "std")]
+pub mod spectral;
+#[cfg(feature = "std")]
+pub mod stats;
 pub mod stimulus;
 pub mod white_point;
 mod xyz;
@@ -622,6 +664,92 @@ where
     }
 }
 
+/// An operator for restricting each of a color's components to a custom range.
+///
+/// This is useful when the valid range is narrower than usual, such as for the
+/// "legal range" of video signals, where 8 bit RGB values are limited to
+/// `[16, 235]`. The ranges are given as `(min, max)` pairs, in the same order
+/// as the components from [`ArrayCast`](cast::ArrayCast), and the result is
+/// not required to be within the regular bounds of the color.
+///
+/// `ClampToRange` is implemented for all types that implement `ArrayCast`.
+///
+/// ```
+/// use palette::{ClampToRange, Srgb};
+///
+/// let legal = (16.0 / 255.0, 235.0 / 255.0);
+/// let color = Srgb::new(1.0, 0.5, 0.0).clamp_to_range([legal; 3]);
+///
+/// assert_eq!(color, Srgb::new(235.0 / 255.0, 0.5, 16.0 / 255.0));
+/// ```
+pub trait ClampToRange<R> {
+    /// Return a new color where each component has been changed to the
+    /// nearest value in its `(min, max)` range.
+    #[must_use]
+    fn clamp_to_range(self, ranges: R) -> Self;
+}
+
+impl<C, T, const N: usize> ClampToRange<[(T, T); N]> for C
+where
+    C: cast::ArrayCast<Array = [T; N]>,
+    T: PartialOrd + Clone,
+{
+    #[inline]
+    fn clamp_to_range(self, ranges: [(T, T); N]) -> Self {
+        let mut components = cast::into_array(self);
+
+        for (component, (min, max)) in components.iter_mut().zip(ranges.iter()) {
+            clamp_assign(component, min.clone(), max.clone());
+        }
+
+        cast::from_array(components)
+    }
+}
+
+/// A constructor for achromatic colors, from a gray sRGB intensity.
+///
+/// The intensity is used for each of the components of an [`Srgb`] color,
+/// which is then converted to `Self`. This makes `0.0` black and `1.0` white,
+/// and it makes the result neutral in any color space with a D65 white point.
+/// `FromGray` is implemented for all types that can be converted from
+/// `Srgb<T>` with [`FromColor`].
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{FromGray, Hsl, Srgb};
+///
+/// assert_eq!(Srgb::gray(0.5), Srgb::new(0.5, 0.5, 0.5));
+/// assert_eq!(Srgb::grey(1.0), Srgb::new(1.0, 1.0, 1.0));
+///
+/// let hsl = Hsl::gray(0.25);
+/// assert_relative_eq!(hsl.saturation, 0.0);
+/// assert_relative_eq!(hsl.lightness, 0.25);
+/// ```
+pub trait FromGray<T>: Sized {
+    /// Create a gray color with the sRGB intensity `value`.
+    #[must_use]
+    fn gray(value: T) -> Self;
+
+    /// Create a grey color with the sRGB intensity `value`. This is the same
+    /// as [`gray`](FromGray::gray).
+    #[must_use]
+    #[inline]
+    fn grey(value: T) -> Self {
+        Self::gray(value)
+    }
+}
+
+impl<C, T> FromGray<T> for C
+where
+    C: FromColor<Srgb<T>>,
+    T: Clone,
+{
+    #[inline]
+    fn gray(value: T) -> Self {
+        C::from_color(Srgb::new(value.clone(), value.clone(), value))
+    }
+}
+
 /// Linear color interpolation of two colors.
 ///
 /// See also [`MixAssign`].
@@ -721,6 +849,42 @@ pub trait Lighten {
     /// ```
     #[must_use]
     fn lighten_fixed(self, amount: Self::Scalar) -> Self;
+
+    /// Lighten the color by `amount`, using either
+    /// [`lighten`](Lighten::lighten) or
+    /// [`lighten_fixed`](Lighten::lighten_fixed), depending on `mode`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Lighten, LightnessMode, Oklab};
+    ///
+    /// let color = Oklab::new(0.8, 0.0, 0.0);
+    /// assert_relative_eq!(color.lighten_with(0.1, LightnessMode::Relative).l, 0.82);
+    /// assert_relative_eq!(color.lighten_with(0.1, LightnessMode::Fixed).l, 0.9);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn lighten_with(self, amount: Self::Scalar, mode: LightnessMode) -> Self
+    where
+        Self: Sized,
+    {
+        match mode {
+            LightnessMode::Relative => self.lighten(amount),
+            LightnessMode::Fixed => self.lighten_fixed(amount),
+        }
+    }
+}
+
+/// Selects how [`Lighten::lighten_with`] and [`Darken::darken_with`] change
+/// the lightness of a color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightnessMode {
+    /// Move the lightness by a fraction of the remaining distance to the
+    /// maximum or minimum lightness. Colors that are already close to that
+    /// limit change less.
+    Relative,
+    /// Move the lightness by a fixed amount, regardless of its current value.
+    Fixed,
 }
 
 /// Assigning operators for lightening a color.
@@ -853,6 +1017,29 @@ pub trait Darken {
     /// ```
     #[must_use]
     fn darken_fixed(self, amount: Self::Scalar) -> Self;
+
+    /// Darken the color by `amount`, using either [`darken`](Darken::darken)
+    /// or [`darken_fixed`](Darken::darken_fixed), depending on `mode`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Darken, LightnessMode, Oklab};
+    ///
+    /// let color = Oklab::new(0.2, 0.0, 0.0);
+    /// assert_relative_eq!(color.darken_with(0.1, LightnessMode::Relative).l, 0.18);
+    /// assert_relative_eq!(color.darken_with(0.1, LightnessMode::Fixed).l, 0.1);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn darken_with(self, amount: Self::Scalar, mode: LightnessMode) -> Self
+    where
+        Self: Sized,
+    {
+        match mode {
+            LightnessMode::Relative => self.darken(amount),
+            LightnessMode::Fixed => self.darken_fixed(amount),
+        }
+    }
 }
 
 impl<T> Darken for T
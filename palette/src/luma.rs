@@ -1,11 +1,17 @@
 //! Luminance types.
 
+mod apca;
 pub mod channels;
+#[cfg(feature = "std")]
+mod grayscale;
 mod luma;
 
 use crate::encoding::{Gamma, Linear, Srgb, TransferFn};
 use crate::white_point::{WhitePoint, D65};
 
+pub use self::apca::apca_contrast;
+#[cfg(feature = "std")]
+pub use self::grayscale::{to_grayscale_buffer, LumaWeights};
 pub use self::luma::{Luma, Lumaa};
 
 /// sRGB encoded luminance.
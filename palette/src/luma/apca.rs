@@ -0,0 +1,149 @@
+use crate::{
+    encoding::Srgb,
+    num::{Abs, Arithmetics, Powf, Real, Zero},
+    rgb::Rgb,
+};
+
+/// Calculate the APCA lightness contrast (Lc) of `text` on `background`.
+///
+/// The Accessible Perceptual Contrast Algorithm (APCA) is a candidate for
+/// replacing the WCAG 2 contrast ratio, which is available as
+/// [`RelativeContrast`](crate::RelativeContrast). It's designed for text and
+/// takes the polarity into account, since light text on a dark background
+/// doesn't appear to have the same contrast as the other way around. This is
+/// the SAPC based calculation from APCA-W3 version 0.0.98G-4g.
+///
+/// The result is roughly within `[-108, 106]`, where positive values are for
+/// dark text on a light background and negative values are for light text on
+/// a dark background. Values near `0` mean that there is too little contrast,
+/// and an absolute value of `75` is a common minimum for body text.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{luma::apca_contrast, Srgb};
+///
+/// let gray = Srgb::new(0x88u8, 0x88, 0x88).into_format();
+/// let white = Srgb::new(1.0, 1.0, 1.0);
+///
+/// assert_relative_eq!(apca_contrast(gray, white), 63.056, epsilon = 0.001);
+/// assert_relative_eq!(apca_contrast(white, gray), -68.541, epsilon = 0.001);
+/// ```
+///
+/// See the [APCA documentation](https://github.com/Myndex/apca-w3) for more
+/// details and guidelines.
+pub fn apca_contrast<T>(text: Rgb<Srgb, T>, background: Rgb<Srgb, T>) -> T
+where
+    T: Real + Zero + Powf + Abs + Arithmetics + PartialOrd + Clone,
+{
+    let text = screen_luminance(text);
+    let background = screen_luminance(background);
+
+    if (background.clone() - &text).abs() < T::from_f64(0.0005) {
+        return T::zero();
+    }
+
+    let low_clip = T::from_f64(0.1);
+    let output = if background > text {
+        // Dark text on a light background.
+        let sapc =
+            (background.powf(T::from_f64(0.56)) - text.powf(T::from_f64(0.57))) * T::from_f64(1.14);
+
+        if sapc < low_clip {
+            return T::zero();
+        }
+
+        sapc - T::from_f64(0.027)
+    } else {
+        // Light text on a dark background.
+        let sapc =
+            (background.powf(T::from_f64(0.65)) - text.powf(T::from_f64(0.62))) * T::from_f64(1.14);
+
+        if sapc > -low_clip {
+            return T::zero();
+        }
+
+        sapc + T::from_f64(0.027)
+    };
+
+    output * T::from_f64(100.0)
+}
+
+/// The estimated screen luminance, with a soft clamp for very dark colors.
+fn screen_luminance<T>(color: Rgb<Srgb, T>) -> T
+where
+    T: Real + Powf + Arithmetics + PartialOrd + Clone,
+{
+    let exponent = T::from_f64(2.4);
+    let luminance = color.red.powf(exponent.clone()) * T::from_f64(0.2126729)
+        + color.green.powf(exponent.clone()) * T::from_f64(0.7151522)
+        + color.blue.powf(exponent) * T::from_f64(0.0721750);
+
+    let black_threshold = T::from_f64(0.022);
+    if luminance < black_threshold {
+        luminance.clone() + (black_threshold - luminance).powf(T::from_f64(1.414))
+    } else {
+        luminance
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::apca_contrast;
+    use crate::Srgb;
+
+    fn contrast(text: u32, background: u32) -> f64 {
+        apca_contrast(
+            Srgb::from(text).into_format(),
+            Srgb::from(background).into_format(),
+        )
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_relative_eq!(
+            contrast(0x888888, 0xffffff),
+            63.056469930209424,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0xffffff, 0x888888),
+            -68.54146436644962,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0x000000, 0xaaaaaa),
+            58.146262578561334,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0xaaaaaa, 0x000000),
+            -56.24113336839742,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0x112233, 0xddeeff),
+            91.66830811481631,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0xddeeff, 0x112233),
+            -93.06770049484275,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0x112233, 0x444444),
+            8.32326136957393,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            contrast(0x444444, 0x112233),
+            -7.526878460278154,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn same_color_has_no_contrast() {
+        assert_eq!(contrast(0x336699, 0x336699), 0.0);
+    }
+}
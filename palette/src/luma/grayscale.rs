@@ -0,0 +1,93 @@
+use crate::{
+    num::{Arithmetics, Real},
+    Srgb, SrgbLuma,
+};
+
+/// The weights of the red, green and blue components when converting RGB to
+/// grayscale luma with [`to_grayscale_buffer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LumaWeights {
+    /// The ITU-R BT.709 weights, `0.2126`, `0.7152` and `0.0722`, which
+    /// match the sRGB primaries.
+    Rec709,
+    /// The ITU-R BT.601 weights, `0.299`, `0.587` and `0.114`, which are
+    /// common in older video and image processing software.
+    Rec601,
+    /// Custom red, green and blue weights, which should add up to `1`.
+    Custom([f64; 3]),
+}
+
+impl LumaWeights {
+    /// Get the red, green and blue weights.
+    pub fn weights(self) -> [f64; 3] {
+        match self {
+            LumaWeights::Rec709 => [0.2126, 0.7152, 0.0722],
+            LumaWeights::Rec601 => [0.299, 0.587, 0.114],
+            LumaWeights::Custom(weights) => weights,
+        }
+    }
+}
+
+/// Convert a buffer of sRGB pixels to grayscale, using `weights`.
+///
+/// Each luma value is the weighted sum of the non-linear red, green and blue
+/// components, as in video and image processing, and not the relative
+/// luminance of the color. This is fast and good enough for preprocessing
+/// images for computer vision. Convert to [`Luma`](crate::Luma) with
+/// [`FromColor`](crate::FromColor) for the relative luminance.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{
+///     luma::{to_grayscale_buffer, LumaWeights},
+///     Srgb,
+/// };
+///
+/// let pixels = [Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.5, 0.5, 0.5)];
+/// let gray = to_grayscale_buffer(&pixels, LumaWeights::Rec601);
+///
+/// assert_relative_eq!(gray[0].luma, 0.299);
+/// assert_relative_eq!(gray[1].luma, 0.5);
+/// ```
+pub fn to_grayscale_buffer<T>(pixels: &[Srgb<T>], weights: LumaWeights) -> Vec<SrgbLuma<T>>
+where
+    T: Real + Arithmetics + Clone,
+{
+    let [red, green, blue] = weights.weights();
+    let (red, green, blue) = (T::from_f64(red), T::from_f64(green), T::from_f64(blue));
+
+    pixels
+        .iter()
+        .map(|pixel| {
+            SrgbLuma::new(
+                pixel.red.clone() * &red
+                    + pixel.green.clone() * &green
+                    + pixel.blue.clone() * &blue,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{to_grayscale_buffer, LumaWeights};
+    use crate::Srgb;
+
+    #[test]
+    fn rec709_luma() {
+        let pixels = [
+            Srgb::new(0.2f64, 0.6, 1.0),
+            Srgb::new(0.0, 0.0, 0.0),
+            Srgb::new(1.0, 1.0, 1.0),
+        ];
+        let gray = to_grayscale_buffer(&pixels, LumaWeights::Rec709);
+
+        assert_eq!(gray.len(), 3);
+        assert_relative_eq!(gray[0].luma, 0.2126 * 0.2 + 0.7152 * 0.6 + 0.0722);
+        assert_relative_eq!(gray[1].luma, 0.0);
+        assert_relative_eq!(gray[2].luma, 1.0);
+
+        let custom = to_grayscale_buffer(&pixels[..1], LumaWeights::Custom([0.0, 0.0, 1.0]));
+        assert_relative_eq!(custom[0].luma, 1.0);
+    }
+}
@@ -24,7 +24,7 @@ use crate::{
     encoding::{linear::LinearFn, Linear, Srgb, TransferFn},
     luma::LumaStandard,
     num::{Arithmetics, IsValidDivisor, MinMax, One, Real, Sqrt, Zero},
-    stimulus::{FromStimulus, Stimulus},
+    stimulus::{try_from_stimulus, FromStimulus, LossyFormatError, Stimulus},
     Alpha, Blend, Clamp, ClampAssign, ComponentWise, IsWithinBounds, Lighten, LightenAssign, Mix,
     MixAssign, RelativeContrast, Xyz, Yxy,
 };
@@ -100,6 +100,20 @@ impl<S, T> Luma<S, T> {
         color.into_format()
     }
 
+    /// Convert into another component type, but only if it can be done
+    /// without losing precision. See
+    /// [`Rgb::try_into_format`](crate::rgb::Rgb::try_into_format).
+    pub fn try_into_format<U>(self) -> Result<Luma<S, U>, LossyFormatError>
+    where
+        T: FromStimulus<U> + PartialEq + Clone,
+        U: FromStimulus<T> + Clone,
+    {
+        Ok(Luma {
+            luma: try_from_stimulus(self.luma)?,
+            standard: PhantomData,
+        })
+    }
+
     /// Convert to a `(luma,)` tuple.
     pub fn into_components(self) -> (T,) {
         (self.luma,)
@@ -312,6 +326,22 @@ impl<S, T, A> Alpha<Luma<S, T>, A> {
         color.into_format()
     }
 
+    /// Convert into another component type, but only if it can be done
+    /// without losing precision. See
+    /// [`Rgb::try_into_format`](crate::rgb::Rgb::try_into_format).
+    pub fn try_into_format<U, B>(self) -> Result<Alpha<Luma<S, U>, B>, LossyFormatError>
+    where
+        T: FromStimulus<U> + PartialEq + Clone,
+        U: FromStimulus<T> + Clone,
+        A: FromStimulus<B> + PartialEq + Clone,
+        B: FromStimulus<A> + Clone,
+    {
+        Ok(Alpha {
+            color: self.color.try_into_format()?,
+            alpha: try_from_stimulus(self.alpha)?,
+        })
+    }
+
     /// Convert to a `(luma, alpha)` tuple.
     pub fn into_components(self) -> (T, A) {
         (self.color.luma, self.alpha)
@@ -1102,6 +1132,40 @@ mod test {
 
     raw_pixel_conversion_tests!(Luma<Srgb>: luma);
 
+    #[test]
+    fn gray_rgb_conversion() {
+        use crate::{FromColor, LinLuma, LinSrgb, SrgbLuma};
+
+        // The same encoding copies the value to every channel.
+        let gray = crate::Srgb::from_color(SrgbLuma::new(0.5f64));
+        assert_eq!(gray, crate::Srgb::new(0.5, 0.5, 0.5));
+        assert_eq!(SrgbLuma::from_color(gray), SrgbLuma::new(0.5));
+
+        // Linear luma is encoded on the way to sRGB and back.
+        let encoded = crate::Srgb::from_color(LinLuma::new(0.5f64));
+        assert_relative_eq!(
+            encoded,
+            crate::Srgb::new(0.735357, 0.735357, 0.735357),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            LinLuma::from_color(encoded),
+            LinLuma::new(0.5),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            LinSrgb::from_color(LinLuma::new(0.5f64)),
+            LinSrgb::new(0.5, 0.5, 0.5),
+            epsilon = 1e-12
+        );
+
+        // Colors become their relative luminance.
+        let red = LinLuma::from_color(crate::Srgb::new(1.0f64, 0.0, 0.0));
+        assert_relative_eq!(red, LinLuma::new(0.2126), epsilon = 0.0001);
+        let green = SrgbLuma::<f64>::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(green.into_linear(), LinLuma::new(0.7152), epsilon = 0.0001);
+    }
+
     #[test]
     fn lower_hex() {
         assert_eq!(format!("{:x}", Luma::<Srgb, u8>::new(161)), "a1");
@@ -141,6 +141,40 @@ where
     }
 }
 
+impl<Wp, T> Luv<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real + Arithmetics + PartialOrd + Clone,
+    Xyz<Wp, T>: FromColorUnclamped<Self>,
+    Self: FromColorUnclamped<Xyz<Wp, T>>,
+{
+    /// Mix `self` and `other` as if they were two lights shining on the same
+    /// spot.
+    ///
+    /// The colors are added together in [`Xyz`], which is linear in the
+    /// amount of light, so mixing red and green light gives yellow. This is
+    /// different from [`Mix`], which moves along a straight line in
+    /// Luv and gives a color between the two. The sum is scaled down to
+    /// the luminance of the white point if it's brighter, while keeping its
+    /// chromaticity.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Luv, FromColor, LinSrgb};
+    ///
+    /// let red = Luv::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+    /// let green = Luv::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+    /// let yellow = LinSrgb::from_color(red.mix_additive(green));
+    ///
+    /// assert_relative_eq!(yellow, LinSrgb::new(1.0, 1.0, 0.0), epsilon = 0.001);
+    /// ```
+    #[must_use]
+    pub fn mix_additive(self, other: Self) -> Self {
+        let sum = Xyz::from_color_unclamped(self).add_lights(Xyz::from_color_unclamped(other));
+        Self::from_color_unclamped(sum)
+    }
+}
+
 ///<span id="Luva"></span>[`Luva`](crate::Luva) implementations.
 impl<Wp, T, A> Alpha<Luv<Wp, T>, A> {
     /// Create a CIE L\*u\*v\* color with transparency.
@@ -163,6 +163,26 @@ macro_rules! impl_array_casts {
             }
         }
 
+        impl<$($ty_param)+> core::ops::Index<usize> for $self_ty<$($self_ty_param),+>
+        $(where $($where)+)?
+        {
+            type Output = $array_item;
+
+            #[inline]
+            fn index(&self, index: usize) -> &$array_item {
+                &AsRef::<[$array_item; $array_len]>::as_ref(self)[index]
+            }
+        }
+
+        impl<$($ty_param)+> core::ops::IndexMut<usize> for $self_ty<$($self_ty_param),+>
+        $(where $($where)+)?
+        {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut $array_item {
+                &mut AsMut::<[$array_item; $array_len]>::as_mut(self)[index]
+            }
+        }
+
         impl<$($ty_param)+> From<$self_ty<$($self_ty_param),+>> for [$array_item; $array_len]
         $(where $($where)+)?
         {
@@ -194,6 +194,16 @@ pub trait IsValidDivisor {
     fn is_valid_divisor(&self) -> bool;
 }
 
+/// Methods for checking if a number is finite.
+pub trait IsFinite {
+    /// Return `true` if `self` is neither infinite nor NaN.
+    ///
+    /// Integers are always finite, while floating point types call
+    /// [`is_finite`][std::primitive::f32::is_finite].
+    #[must_use]
+    fn is_finite(&self) -> bool;
+}
+
 /// Methods for calculating the lengths of a hypotenuse.
 pub trait Hypot {
     /// Returns the length of the hypotenuse formed by `self` and `other`, i.e.
@@ -268,6 +278,13 @@ macro_rules! impl_uint {
                     *self != 0
                 }
             }
+
+            impl IsFinite for $ty {
+                #[inline]
+                fn is_finite(&self) -> bool {
+                    true
+                }
+            }
         )+
     };
 }
@@ -331,6 +348,13 @@ macro_rules! impl_float {
                 }
             }
 
+            impl IsFinite for $ty {
+                #[inline]
+                fn is_finite(&self) -> bool {
+                    $ty::is_finite(*self)
+                }
+            }
+
             #[cfg(feature = "std")]
             impl Trigonometry for $ty {
                 #[inline]
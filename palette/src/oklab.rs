@@ -1,4 +1,7 @@
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::{
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
@@ -15,8 +18,9 @@ use crate::{
     angle::RealAngle,
     clamp, clamp_assign, contrast_ratio,
     convert::FromColorUnclamped,
+    css::{self, ParseCssError},
     matrix::multiply_xyz,
-    num::{Arithmetics, Cbrt, MinMax, One, Real, Trigonometry, Zero},
+    num::{Arithmetics, Cbrt, MinMax, One, Real, Round, Sqrt, Trigonometry, Zero},
     white_point::D65,
     Alpha, Clamp, ClampAssign, ComponentWise, FromColor, GetHue, IsWithinBounds, Lighten,
     LightenAssign, Mat3, Mix, MixAssign, OklabHue, Oklch, RelativeContrast, Xyz,
@@ -154,6 +158,101 @@ where
     }
 }
 
+// The constants of the "toe" function from Björn Ottosson's Okhsl and Okhsv,
+// which makes the Oklab lightness match the CIE L* lightness more closely.
+const TOE_K1: f64 = 0.206;
+const TOE_K2: f64 = 0.03;
+const TOE_K3: f64 = (1.0 + TOE_K1) / (1.0 + TOE_K2);
+
+impl<T> Oklab<T>
+where
+    T: Real + Sqrt + Arithmetics + Clone,
+{
+    /// Map the position `t` of a lightness slider, from `0` to `1`, to an
+    /// Oklab lightness.
+    ///
+    /// The Oklab lightness is a bit too bright in the dark end to feel even
+    /// on a slider. This uses the same adjustment as Okhsl and Okhsv, which
+    /// makes the slider steps match the CIE L\* lightness, where the
+    /// position is `L* / 100`. The ends are `0` and `1` for both.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Oklab;
+    ///
+    /// let l = Oklab::slider_to_lightness(0.5);
+    /// assert!(l > 0.5);
+    /// assert_relative_eq!(Oklab::lightness_to_slider(l), 0.5, epsilon = 0.00001);
+    /// ```
+    pub fn slider_to_lightness(t: T) -> T {
+        let k1 = T::from_f64(TOE_K1);
+        let k2 = T::from_f64(TOE_K2);
+        let k3 = T::from_f64(TOE_K3);
+
+        (t.clone() * &t + k1 * &t) / (k3 * (t + k2))
+    }
+
+    /// Map an Oklab lightness to the position of a lightness slider, from
+    /// `0` to `1`. This is the inverse of
+    /// [`slider_to_lightness`](Oklab::slider_to_lightness).
+    pub fn lightness_to_slider(l: T) -> T {
+        let k1 = T::from_f64(TOE_K1);
+        let k2 = T::from_f64(TOE_K2);
+        let k3 = T::from_f64(TOE_K3);
+
+        let offset = k3.clone() * &l - &k1;
+        let root = (offset.clone() * &offset + T::from_f64(4.0) * k2 * k3 * l).sqrt();
+        T::from_f64(0.5) * (offset + root)
+    }
+}
+
+impl<T> Oklab<T>
+where
+    T: Real + Round + Arithmetics + Into<f64> + Clone,
+{
+    /// Find the cell of a uniform grid that the color is in, where each cell
+    /// is `delta_e_step` wide along each axis.
+    ///
+    /// The distance in Oklab is a measure of the perceived difference, so the
+    /// grid divides the colors into perceptually even groups. This is useful
+    /// for perceptual color compression, where the cell indices are stored
+    /// instead of the colors. Colors in the same cell are at most
+    /// `delta_e_step * √3` apart. Use
+    /// [`from_perceptual_quantized`](Oklab::from_perceptual_quantized) to get
+    /// a color back.
+    ///
+    /// ```
+    /// use palette::Oklab;
+    ///
+    /// let a = Oklab::new(0.512, 0.031, -0.044);
+    /// let b = Oklab::new(0.518, 0.036, -0.041);
+    ///
+    /// assert_eq!(a.perceptual_quantize(0.02), [25, 1, -3]);
+    /// assert_eq!(a.perceptual_quantize(0.02), b.perceptual_quantize(0.02));
+    /// ```
+    #[must_use]
+    pub fn perceptual_quantize(self, delta_e_step: T) -> [i32; 3] {
+        let index = |value: T| (value / delta_e_step.clone()).floor().into() as i32;
+        [index(self.l), index(self.a), index(self.b)]
+    }
+
+    /// Get the color in the middle of a grid cell from
+    /// [`perceptual_quantize`](Oklab::perceptual_quantize).
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::Oklab;
+    ///
+    /// let color = Oklab::from_perceptual_quantized([25, 1, -3], 0.02);
+    /// assert_relative_eq!(color, Oklab::new(0.51, 0.03, -0.05));
+    /// ```
+    #[must_use]
+    pub fn from_perceptual_quantized(indices: [i32; 3], delta_e_step: T) -> Self {
+        let value = |index: i32| T::from_f64(f64::from(index) + 0.5) * delta_e_step.clone();
+        Oklab::new(value(indices[0]), value(indices[1]), value(indices[2]))
+    }
+}
+
 ///<span id="Oklaba"></span>[`Oklaba`](crate::Oklaba) implementations.
 impl<T, A> Alpha<Oklab<T>, A> {
     /// Create an Oklab color with transparency.
@@ -243,6 +342,34 @@ impl<T, A> From<Alpha<Oklab<T>, A>> for (T, T, T, A) {
     }
 }
 
+impl<T> FromStr for Oklab<T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `oklab()` color, such as `oklab(0.6 -0.1 0.1)`. See the
+    /// [`css`](crate::css) module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Oklab<T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["oklab"];
+
+    fn from_components([l, a, b]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Oklab::new(
+            T::from_f64(css::parse_number(l, 1.0)?),
+            T::from_f64(css::parse_number(a, 0.4)?),
+            T::from_f64(css::parse_number(b, 0.4)?),
+        ))
+    }
+}
+
 impl<T> IsWithinBounds for Oklab<T>
 where
     T: Real + PartialOrd,
@@ -440,7 +567,71 @@ unsafe impl<T> bytemuck::Pod for Oklab<T> where T: bytemuck::Pod {}
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{FromColor, LinSrgb};
+    use crate::{FromColor, Lab, Lighten, LightnessMode, LinSrgb};
+
+    #[test]
+    fn lightness_slider() {
+        assert_relative_eq!(Oklab::slider_to_lightness(0.0f64), 0.0);
+        assert_relative_eq!(Oklab::slider_to_lightness(1.0f64), 1.0);
+        assert_relative_eq!(Oklab::lightness_to_slider(0.0f64), 0.0);
+        assert_relative_eq!(Oklab::lightness_to_slider(1.0f64), 1.0, epsilon = 1e-12);
+
+        let mut previous = -1.0;
+        for i in 0..=100 {
+            let t = f64::from(i) / 100.0;
+            let l = Oklab::slider_to_lightness(t);
+            assert!(l > previous);
+            assert_relative_eq!(Oklab::lightness_to_slider(l), t, epsilon = 1e-12);
+            previous = l;
+        }
+
+        // The slider follows L*, so mid gray is in the middle.
+        let gray = Lab::<crate::white_point::D65, f64>::new(50.0, 0.0, 0.0);
+        let gray = Oklab::from_color(gray);
+        assert_relative_eq!(Oklab::lightness_to_slider(gray.l), 0.5, epsilon = 0.01);
+    }
+
+    #[test]
+    fn perceptual_quantize_same_cell() {
+        let step = 0.05f64;
+        let a = Oklab::new(0.61, 0.02, -0.07);
+        let b = Oklab::new(0.64, 0.04, -0.09);
+        let c = Oklab::new(0.66, 0.04, -0.09);
+
+        assert_eq!(a.perceptual_quantize(step), b.perceptual_quantize(step));
+        assert_ne!(a.perceptual_quantize(step), c.perceptual_quantize(step));
+
+        let center = Oklab::from_perceptual_quantized(a.perceptual_quantize(step), step);
+        assert_eq!(
+            center.perceptual_quantize(step),
+            a.perceptual_quantize(step)
+        );
+        assert!((center.l - a.l).abs() <= step / 2.0);
+    }
+
+    #[test]
+    fn lighten_modes() {
+        let dark = Oklab::new(0.2, 0.0, 0.0);
+        let light = Oklab::new(0.9, 0.0, 0.0);
+
+        let dark_relative = dark.lighten_with(0.5, LightnessMode::Relative);
+        let light_relative = light.lighten_with(0.5, LightnessMode::Relative);
+        assert!(light_relative.l - light.l < dark_relative.l - dark.l);
+        assert_relative_eq!(dark_relative.l, 0.6);
+        assert_relative_eq!(light_relative.l, 0.95);
+
+        let dark_fixed = dark.lighten_with(0.05, LightnessMode::Fixed);
+        let light_fixed = light.lighten_with(0.05, LightnessMode::Fixed);
+        assert_relative_eq!(dark_fixed.l - dark.l, light_fixed.l - light.l);
+    }
+
+    #[test]
+    fn from_str() {
+        let oklab: Oklab = "oklab(60% -0.1 25%)".parse().unwrap();
+        assert_relative_eq!(oklab, Oklab::new(0.6, -0.1, 0.1));
+
+        assert!("lab(60 -0.1 0.1)".parse::<Oklab>().is_err());
+    }
 
     #[test]
     fn red() {
@@ -1,4 +1,7 @@
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 #[cfg(feature = "random")]
@@ -16,7 +19,8 @@ use crate::num::Sqrt;
 use crate::{
     angle::{RealAngle, SignedAngle},
     clamp, clamp_assign, contrast_ratio,
-    convert::FromColorUnclamped,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    css::{self, ParseCssError},
     num::{Arithmetics, Hypot, MinMax, One, Real, Zero},
     white_point::D65,
     Alpha, Clamp, ClampAssign, FromColor, GetHue, IsWithinBounds, Lighten, LightenAssign, Mix,
@@ -123,6 +127,38 @@ where
     }
 }
 
+impl<T> Oklch<T> {
+    /// Derive a color from `base`, by changing its lightness, chroma and hue
+    /// with `f`.
+    ///
+    /// This is the same as the relative color syntax in CSS, where
+    /// `oklch(from var(--base) calc(l + 0.1) c h)` makes a lighter version of
+    /// the base color. `base` can be in any color space that can be converted
+    /// to `Oklch`, and the result is not clamped.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Oklch, Srgb};
+    ///
+    /// let base = Srgb::new(0.2f32, 0.4, 0.8).into_linear();
+    /// let lighter = Oklch::relative(base, |l, c, h| (l + 0.1, c, h));
+    /// let complement = Oklch::relative(base, |l, c, h| (l, c, h + 180.0));
+    ///
+    /// let base = Oklch::relative(base, |l, c, h| (l, c, h));
+    /// assert_relative_eq!(lighter.l, base.l + 0.1);
+    /// assert_relative_eq!(complement.hue, base.hue + 180.0);
+    /// ```
+    pub fn relative<C, F>(base: C, f: F) -> Self
+    where
+        C: IntoColorUnclamped<Self>,
+        F: FnOnce(T, T, OklabHue<T>) -> (T, T, OklabHue<T>),
+    {
+        let Oklch { l, chroma, hue } = base.into_color_unclamped();
+        let (l, chroma, hue) = f(l, chroma, hue);
+        Oklch::new(l, chroma, hue)
+    }
+}
+
 ///<span id="Oklcha"></span>[`Oklcha`](crate::Oklcha) implementations.
 impl<T, A> Alpha<Oklch<T>, A> {
     /// Create an Oklch color with transparency.
@@ -206,6 +242,34 @@ impl<T, A> From<Alpha<Oklch<T>, A>> for (T, T, OklabHue<T>, A) {
     }
 }
 
+impl<T> FromStr for Oklch<T>
+where
+    T: Real,
+{
+    type Err = ParseCssError;
+
+    /// Parse a CSS `oklch()` color, such as `oklch(0.6 0.1 120deg)`. See the
+    /// [`css`](crate::css) module for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        css::parse_function(s)
+    }
+}
+
+impl<T> css::CssFunction for Oklch<T>
+where
+    T: Real,
+{
+    const NAMES: &'static [&'static str] = &["oklch"];
+
+    fn from_components([l, chroma, hue]: [&str; 3]) -> Result<Self, ParseCssError> {
+        Ok(Oklch::new(
+            T::from_f64(css::parse_number(l, 1.0)?),
+            T::from_f64(css::parse_number(chroma, 0.4)?),
+            T::from_f64(css::parse_hue(hue)?),
+        ))
+    }
+}
+
 impl<T> IsWithinBounds for Oklch<T>
 where
     T: Zero + One + PartialOrd,
@@ -441,7 +505,27 @@ unsafe impl<T> bytemuck::Pod for Oklch<T> where T: bytemuck::Pod {}
 
 #[cfg(test)]
 mod test {
-    use crate::Oklch;
+    use crate::{FromColor, Oklab, Oklch};
+
+    #[test]
+    fn relative_lighter_variant() {
+        let base = Oklch::new(0.5, 0.1, 250.0);
+        let lighter = Oklch::relative(base, |l, c, h| (l + 0.2, c * 0.5, h));
+
+        assert_relative_eq!(lighter, Oklch::new(0.7, 0.05, 250.0), epsilon = 0.00001);
+
+        let from_oklab = Oklch::relative(Oklab::from_color(base), |l, c, h| (l + 0.2, c, h));
+        assert_relative_eq!(from_oklab, Oklch::new(0.7, 0.1, 250.0), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn from_str() {
+        let oklch: Oklch = "oklch(0.6 50% none)".parse().unwrap();
+        assert_relative_eq!(oklch, Oklch::new(0.6, 0.2, 0.0));
+
+        assert!("oklab(0.6 0.2 0)".parse::<Oklch>().is_err());
+        assert!("oklch(0.6 0.2 0 / 0.5)".parse::<Oklch>().is_err());
+    }
 
     #[test]
     fn ranges() {
@@ -0,0 +1,40 @@
+//! The most commonly used traits, for importing all at once.
+//!
+//! The color types are not included, since they are often used through their
+//! own aliases, such as [`Srgb`](crate::Srgb).
+//!
+//! ```
+//! use palette::{prelude::*, LinSrgb, Lch, Srgb};
+//!
+//! let color: Lch = Srgb::new(0.8, 0.2, 0.1).into_linear().into_color();
+//! let lighter = color.lighten(0.1);
+//! assert!(lighter.is_within_bounds());
+//!
+//! let mixed = LinSrgb::new(1.0, 0.0, 0.0).mix(LinSrgb::new(0.0, 0.0, 1.0), 0.5);
+//! assert_eq!(mixed, LinSrgb::new(0.5, 0.0, 0.5));
+//! ```
+
+pub use crate::convert::{FromColor, FromColorUnclamped, IntoColor, IntoColorUnclamped};
+pub use crate::{
+    Clamp, ClampAssign, Darken, DarkenAssign, IsWithinBounds, Lighten, LightenAssign, Mix,
+    MixAssign, WithAlpha,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Hsv, LinSrgb, LinSrgba, Srgb};
+
+    #[test]
+    fn convert_and_mix() {
+        let hsv: Hsv = Srgb::new(1.0, 0.0, 0.0).into_color();
+        assert_eq!(hsv, Hsv::new(0.0, 1.0, 1.0));
+
+        let linear = LinSrgb::from_color(Srgb::new(1.0, 1.0, 1.0));
+        let mixed = linear.mix(LinSrgb::new(0.0, 0.0, 0.0), 0.25);
+        assert_eq!(mixed, LinSrgb::new(0.75, 0.75, 0.75));
+
+        let opaque: LinSrgba = mixed.opaque();
+        assert!(opaque.darken(0.5).is_within_bounds());
+    }
+}
@@ -69,6 +69,7 @@ use crate::{
 
 pub use self::rgb::{FromHexError, Rgb, Rgba};
 
+pub mod ansi;
 pub mod channels;
 mod rgb;
 
@@ -0,0 +1,172 @@
+//! Mapping to the color palettes of ANSI terminals.
+//!
+//! Many terminals can't show arbitrary RGB colors, and are limited to a
+//! palette of 256 or 16 colors. The functions in this module find the closest
+//! entry in the default xterm palettes, measured as the squared distance
+//! between the non-linear sRGB components.
+//!
+//! ```
+//! use palette::{rgb::ansi, Srgb};
+//!
+//! let orange = Srgb::new(255u8, 135, 0);
+//!
+//! assert_eq!(ansi::to_ansi256(orange), 208);
+//! assert_eq!(ansi::to_ansi16(orange), 3);
+//! ```
+
+use crate::Srgb;
+
+/// The default xterm colors for the 16 basic ANSI colors.
+const XTERM_16: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+/// The component levels of the 6×6×6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Find the closest color in the 256 color xterm palette.
+///
+/// Only the 6×6×6 color cube (indices 16 to 231) and the gray ramp (indices
+/// 232 to 255) are considered. The first 16 colors are left out, since they
+/// are often changed by terminal themes. Use [`to_ansi16`] for those.
+///
+/// ```
+/// use palette::{rgb::ansi::to_ansi256, Srgb};
+///
+/// assert_eq!(to_ansi256(Srgb::new(255u8, 0, 0)), 196);
+/// assert_eq!(to_ansi256(Srgb::new(128u8, 128, 128)), 244);
+/// ```
+#[must_use]
+pub fn to_ansi256(color: Srgb<u8>) -> u8 {
+    let components = [color.red, color.green, color.blue];
+
+    // The closest cube color is made from the closest level of each component.
+    let cube_index = [
+        closest_cube_level(color.red),
+        closest_cube_level(color.green),
+        closest_cube_level(color.blue),
+    ];
+    let cube_color = [
+        CUBE_LEVELS[usize::from(cube_index[0])],
+        CUBE_LEVELS[usize::from(cube_index[1])],
+        CUBE_LEVELS[usize::from(cube_index[2])],
+    ];
+    let cube_distance = distance_squared(components, cube_color);
+
+    // The gray ramp goes from 8 to 238 in steps of 10.
+    let average = (u16::from(color.red) + u16::from(color.green) + u16::from(color.blue)) / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + gray_index * 10;
+    let gray_distance = distance_squared(components, [gray_level; 3]);
+
+    if gray_distance < cube_distance {
+        232 + gray_index
+    } else {
+        16 + 36 * cube_index[0] + 6 * cube_index[1] + cube_index[2]
+    }
+}
+
+/// Find the closest color among the 16 basic ANSI colors, using the default
+/// xterm palette.
+///
+/// ```
+/// use palette::{rgb::ansi::to_ansi16, Srgb};
+///
+/// assert_eq!(to_ansi16(Srgb::new(255u8, 0, 0)), 9);
+/// assert_eq!(to_ansi16(Srgb::new(10u8, 10, 10)), 0);
+/// ```
+#[must_use]
+pub fn to_ansi16(color: Srgb<u8>) -> u8 {
+    let components = [color.red, color.green, color.blue];
+
+    let mut closest = 0;
+    let mut closest_distance = u32::MAX;
+
+    for (index, &entry) in XTERM_16.iter().enumerate() {
+        let distance = distance_squared(components, entry);
+
+        if distance < closest_distance {
+            closest = index as u8;
+            closest_distance = distance;
+        }
+    }
+
+    closest
+}
+
+fn closest_cube_level(value: u8) -> u8 {
+    // The midpoints between the levels.
+    match value {
+        0..=47 => 0,
+        48..=114 => 1,
+        115..=154 => 2,
+        155..=194 => 3,
+        195..=234 => 4,
+        _ => 5,
+    }
+}
+
+fn distance_squared(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(&b)
+        .map(|(&a, &b)| {
+            let difference = i32::from(a) - i32::from(b);
+            (difference * difference) as u32
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{to_ansi16, to_ansi256, CUBE_LEVELS, XTERM_16};
+    use crate::Srgb;
+
+    #[test]
+    fn pure_colors() {
+        assert_eq!(to_ansi256(Srgb::new(0, 0, 0)), 16);
+        assert_eq!(to_ansi256(Srgb::new(255, 255, 255)), 231);
+        assert_eq!(to_ansi256(Srgb::new(0, 255, 0)), 46);
+        assert_eq!(to_ansi256(Srgb::new(0, 0, 255)), 21);
+        assert_eq!(to_ansi256(Srgb::new(238, 238, 238)), 255);
+
+        assert_eq!(to_ansi16(Srgb::new(0, 255, 0)), 10);
+        assert_eq!(to_ansi16(Srgb::new(0, 0, 238)), 4);
+        assert_eq!(to_ansi16(Srgb::new(255, 255, 255)), 15);
+    }
+
+    #[test]
+    fn palette_entries_map_to_themselves() {
+        for (index, &[red, green, blue]) in XTERM_16.iter().enumerate() {
+            assert_eq!(to_ansi16(Srgb::new(red, green, blue)), index as u8);
+        }
+
+        for (r, &red) in CUBE_LEVELS.iter().enumerate() {
+            for (g, &green) in CUBE_LEVELS.iter().enumerate() {
+                for (b, &blue) in CUBE_LEVELS.iter().enumerate() {
+                    let expected = 16 + 36 * r + 6 * g + b;
+                    assert_eq!(to_ansi256(Srgb::new(red, green, blue)), expected as u8);
+                }
+            }
+        }
+
+        for index in 0..24u8 {
+            let level = 8 + index * 10;
+            assert_eq!(to_ansi256(Srgb::new(level, level, level)), 232 + index);
+        }
+    }
+}
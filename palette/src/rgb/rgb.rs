@@ -24,15 +24,15 @@ use crate::{
     cast::{ComponentOrder, Packed},
     clamp, clamp_assign, contrast_ratio,
     convert::{FromColorUnclamped, IntoColorUnclamped},
-    encoding::{linear::LinearFn, Linear, Srgb},
+    encoding::{linear::LinearFn, srgb::u8_into_linear_f32, Linear, Srgb},
     luma::LumaStandard,
     matrix::{matrix_inverse, multiply_xyz_to_rgb, rgb_to_xyz_matrix},
     num::{Abs, Arithmetics, IsValidDivisor, MinMax, One, Real, Recip, Sqrt, Trigonometry, Zero},
     rgb::{RgbSpace, RgbStandard, TransferFn},
-    stimulus::{FromStimulus, Stimulus},
+    stimulus::{try_from_stimulus, FromStimulus, LossyFormatError, Stimulus},
     white_point::Any,
     Blend, Clamp, ClampAssign, ComponentWise, FromColor, GetHue, Hsl, Hsv, IsWithinBounds, Lighten,
-    LightenAssign, Luma, Mix, MixAssign, RelativeContrast, RgbHue, Xyz, Yxy,
+    LightenAssign, Luma, Mix, MixAssign, Oklab, RelativeContrast, RgbHue, Xyz, Yxy,
 };
 
 /// Generic RGB with an alpha component. See the [`Rgba` implementation in
@@ -123,6 +123,36 @@ impl<S, T> Rgb<S, T> {
         color.into_format()
     }
 
+    /// Convert into another component type, but only if it can be done
+    /// without losing precision.
+    ///
+    /// An error is returned if any of the components would be different after
+    /// converting them back to `T`. This makes it possible to narrow the
+    /// components, for example from `u16` to `u8`, when they are known to
+    /// have been widened before.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// let wide = Srgb::new(0xABABu16, 0x0000, 0xFFFF);
+    /// assert_eq!(wide.try_into_format::<u8>(), Ok(Srgb::new(0xAB, 0x00, 0xFF)));
+    ///
+    /// let detailed = Srgb::new(0x1234u16, 0x0000, 0xFFFF);
+    /// assert!(detailed.try_into_format::<u8>().is_err());
+    /// ```
+    pub fn try_into_format<U>(self) -> Result<Rgb<S, U>, LossyFormatError>
+    where
+        T: FromStimulus<U> + PartialEq + Clone,
+        U: FromStimulus<T> + Clone,
+    {
+        Ok(Rgb {
+            red: try_from_stimulus(self.red)?,
+            green: try_from_stimulus(self.green)?,
+            blue: try_from_stimulus(self.blue)?,
+            standard: PhantomData,
+        })
+    }
+
     /// Convert to a `(red, green, blue)` tuple.
     pub fn into_components(self) -> (T, T, T) {
         (self.red, self.green, self.blue)
@@ -227,6 +257,33 @@ impl<S> Rgb<S, u8> {
     }
 }
 
+impl Rgb<Srgb, u8> {
+    /// Convert the color to linear RGB with `f32` components.
+    ///
+    /// This gives the same result as `color.into_format().into_linear()`, but
+    /// uses a precomputed lookup table for the sRGB transfer function. That
+    /// makes it a lot faster when converting many colors, such as the pixels
+    /// of an image.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let color = Srgb::new(23u8, 198, 76);
+    /// let linear: LinSrgb = color.into_format().into_linear();
+    ///
+    /// assert_relative_eq!(color.into_linear_f32(), linear, epsilon = 0.00001);
+    /// ```
+    #[inline]
+    pub fn into_linear_f32(self) -> Rgb<Linear<Srgb>, f32> {
+        Rgb::new(
+            u8_into_linear_f32(self.red),
+            u8_into_linear_f32(self.green),
+            u8_into_linear_f32(self.blue),
+        )
+    }
+}
+
 impl<S: RgbStandard<T>, T> Rgb<S, T> {
     /// Convert the color to linear RGB.
     pub fn into_linear(self) -> Rgb<Linear<S::Space>, T> {
@@ -269,6 +326,54 @@ impl<S: RgbStandard<T>, T> Rgb<S, T> {
             S::TransferFn::from_linear(St::TransferFn::into_linear(color.blue)),
         )
     }
+
+    /// Relabel the color as linear RGB, without changing its components.
+    ///
+    /// This is not a conversion. The component values are kept exactly as
+    /// they are, so the color will look different if it was correctly
+    /// labeled as non-linear. It's meant for fixing data that was labeled
+    /// wrong, such as an image buffer that a decoder claimed was sRGB encoded,
+    /// even though it's already linear. Use
+    /// [`into_linear`](Rgb::into_linear) to convert the color.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let mislabeled = Srgb::new(0.2f32, 0.5, 0.8);
+    /// let linear: LinSrgb = mislabeled.reinterpret_linear();
+    ///
+    /// assert_eq!(linear, LinSrgb::new(0.2, 0.5, 0.8));
+    /// ```
+    #[inline]
+    pub fn reinterpret_linear(self) -> Rgb<Linear<S::Space>, T> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+
+    /// Relabel the color as having the encoding `St`, without changing its
+    /// components.
+    ///
+    /// This is not a conversion. The component values are kept exactly as
+    /// they are, so the color will look different if it was correctly
+    /// labeled before. It's meant for fixing data that was labeled wrong,
+    /// such as an image buffer that a decoder claimed was linear, even though
+    /// it's sRGB encoded. Use [`into_encoding`](Rgb::into_encoding) to
+    /// convert the color.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let mislabeled = LinSrgb::new(0.2f32, 0.5, 0.8);
+    /// let encoded: Srgb = mislabeled.reinterpret_encoded();
+    ///
+    /// assert_eq!(encoded, Srgb::new(0.2, 0.5, 0.8));
+    /// ```
+    #[inline]
+    pub fn reinterpret_encoded<St>(self) -> Rgb<St, T>
+    where
+        St: RgbStandard<T, Space = S::Space>,
+    {
+        Rgb::new(self.red, self.green, self.blue)
+    }
 }
 
 impl<S, T> Rgb<S, T>
@@ -290,6 +395,126 @@ where
     }
 }
 
+impl<S, T> Rgb<S, T>
+where
+    T: Real + Zero + One + Arithmetics + PartialOrd + Clone,
+    Rgb<S, T>: IntoColorUnclamped<Oklab<T>>,
+{
+    /// Find the closest color on the surface of the RGB gamut, as measured in
+    /// [`Oklab`].
+    ///
+    /// The result always has at least one component at exactly `0.0` or
+    /// `1.0`, and all components within that range. This is different from
+    /// [`clamp`](crate::Clamp::clamp), which changes each component on its
+    /// own and leaves colors that are already inside the gamut unchanged.
+    /// Colors inside the gamut are instead moved out to the nearest point on
+    /// its surface, which is useful for visualizing the gamut boundary.
+    ///
+    /// The search goes through each face of the RGB cube, with a coarse grid
+    /// that is refined around the best point, so it's relatively expensive.
+    ///
+    /// ```
+    /// use palette::{convert::FromColorUnclamped, Oklch, Srgb};
+    ///
+    /// let vivid = Srgb::from_color_unclamped(Oklch::new(0.8, 0.3, 150.0));
+    /// let projected = vivid.project_to_gamut_surface();
+    ///
+    /// let on_surface = |value: f32| value == 0.0 || value == 1.0;
+    /// assert!(on_surface(projected.red) || on_surface(projected.green) || on_surface(projected.blue));
+    /// ```
+    #[must_use]
+    pub fn project_to_gamut_surface(self) -> Self {
+        // The number of cells along each side of the initial grid, and the
+        // number of times the step size is halved while refining.
+        const GRID_SIZE: u32 = 16;
+        const REFINEMENTS: u32 = 12;
+
+        let target: Oklab<T> = self.into_color_unclamped();
+        let distance = |color: Self| {
+            let color: Oklab<T> = color.into_color_unclamped();
+            let l = color.l - &target.l;
+            let a = color.a - &target.a;
+            let b = color.b - &target.b;
+            l.clone() * l + a.clone() * a + b.clone() * b
+        };
+
+        let mut closest: Option<(Self, T)> = None;
+
+        for axis in 0..3 {
+            for side in [T::zero(), T::one()].iter() {
+                let point = |u: T, w: T| {
+                    let side = side.clone();
+                    let [red, green, blue] = match axis {
+                        0 => [side, u, w],
+                        1 => [u, side, w],
+                        _ => [u, w, side],
+                    };
+                    Rgb::new(red, green, blue)
+                };
+
+                let grid_step = T::one() / T::from_f64(GRID_SIZE.into());
+                let mut best = (T::zero(), T::zero(), distance(point(T::zero(), T::zero())));
+
+                for i in 0..=GRID_SIZE {
+                    for j in 0..=GRID_SIZE {
+                        let u = T::from_f64(i.into()) * &grid_step;
+                        let w = T::from_f64(j.into()) * &grid_step;
+                        let candidate = distance(point(u.clone(), w.clone()));
+
+                        if candidate < best.2 {
+                            best = (u, w, candidate);
+                        }
+                    }
+                }
+
+                let mut step = grid_step;
+                for _ in 0..REFINEMENTS {
+                    loop {
+                        let (u, w, _) = best.clone();
+                        let neighbors = [
+                            (u.clone() + &step, w.clone()),
+                            (u.clone() - &step, w.clone()),
+                            (u.clone(), w.clone() + &step),
+                            (u, w - &step),
+                        ];
+
+                        let mut improved = false;
+                        for (u, w) in neighbors.iter() {
+                            let u = clamp(u.clone(), T::zero(), T::one());
+                            let w = clamp(w.clone(), T::zero(), T::one());
+                            let candidate = distance(point(u.clone(), w.clone()));
+
+                            if candidate < best.2 {
+                                best = (u, w, candidate);
+                                improved = true;
+                            }
+                        }
+
+                        if !improved {
+                            break;
+                        }
+                    }
+
+                    step = step / T::from_f64(2.0);
+                }
+
+                let (u, w, best_distance) = best;
+                let is_closer = match &closest {
+                    Some((_, closest_distance)) => best_distance < *closest_distance,
+                    None => true,
+                };
+
+                if is_closer {
+                    closest = Some((point(u, w), best_distance));
+                }
+            }
+        }
+
+        let (color, _) = closest.expect("there should be six faces");
+        color
+    }
+}
+
 /// <span id="Rgba"></span>[`Rgba`](crate::rgb::Rgba) implementations.
 impl<S, T, A> Alpha<Rgb<S, T>, A> {
     /// Non-linear RGB.
@@ -321,6 +546,21 @@ impl<S, T, A> Alpha<Rgb<S, T>, A> {
         color.into_format()
     }
 
+    /// Convert into another component type, but only if it can be done
+    /// without losing precision. See [`Rgb::try_into_format`].
+    pub fn try_into_format<U, B>(self) -> Result<Alpha<Rgb<S, U>, B>, LossyFormatError>
+    where
+        T: FromStimulus<U> + PartialEq + Clone,
+        U: FromStimulus<T> + Clone,
+        A: FromStimulus<B> + PartialEq + Clone,
+        B: FromStimulus<A> + Clone,
+    {
+        Ok(Alpha {
+            color: self.color.try_into_format()?,
+            alpha: try_from_stimulus(self.alpha)?,
+        })
+    }
+
     /// Convert to a `(red, green, blue, alpha)` tuple.
     pub fn into_components(self) -> (T, T, T, A) {
         (
@@ -946,6 +1186,62 @@ impl<S, T, A> From<Alpha<Rgb<S, T>, A>> for (T, T, T, A) {
     }
 }
 
+#[cfg(feature = "glam")]
+impl<S> From<glam::Vec3> for Rgb<S, f32> {
+    fn from(vector: glam::Vec3) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<S> From<Rgb<S, f32>> for glam::Vec3 {
+    fn from(color: Rgb<S, f32>) -> glam::Vec3 {
+        glam::Vec3::new(color.red, color.green, color.blue)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<S> From<glam::Vec4> for Alpha<Rgb<S, f32>, f32> {
+    fn from(vector: glam::Vec4) -> Self {
+        Self::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<S> From<Alpha<Rgb<S, f32>, f32>> for glam::Vec4 {
+    fn from(color: Alpha<Rgb<S, f32>, f32>) -> glam::Vec4 {
+        glam::Vec4::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<S> From<nalgebra::Vector3<f32>> for Rgb<S, f32> {
+    fn from(vector: nalgebra::Vector3<f32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<S> From<Rgb<S, f32>> for nalgebra::Vector3<f32> {
+    fn from(color: Rgb<S, f32>) -> nalgebra::Vector3<f32> {
+        nalgebra::Vector3::new(color.red, color.green, color.blue)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<S> From<nalgebra::Vector4<f32>> for Alpha<Rgb<S, f32>, f32> {
+    fn from(vector: nalgebra::Vector4<f32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<S> From<Alpha<Rgb<S, f32>, f32>> for nalgebra::Vector4<f32> {
+    fn from(color: Alpha<Rgb<S, f32>, f32>) -> nalgebra::Vector4<f32> {
+        nalgebra::Vector4::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
 impl_array_casts!(Rgb<S, T>, [T; 3]);
 
 impl_eq!(Rgb<S>, [red, green, blue]);
@@ -1236,6 +1532,72 @@ mod test {
     use super::{Rgb, Rgba};
     use crate::encoding::Srgb;
     use crate::rgb::channels;
+    use crate::ClampToRange;
+
+    #[test]
+    fn reinterpret_keeps_components() {
+        use core::any::Any;
+
+        use crate::LinSrgb;
+
+        let encoded = Rgb::<Srgb>::new(0.1, 0.5, 0.9);
+        let linear = encoded.reinterpret_linear();
+        assert!((&linear as &dyn Any).is::<LinSrgb>());
+        assert_eq!(
+            (linear.red, linear.green, linear.blue),
+            (encoded.red, encoded.green, encoded.blue)
+        );
+
+        let encoded_again: Rgb<Srgb> = linear.reinterpret_encoded();
+        assert_eq!(encoded_again, encoded);
+        assert_ne!(linear, encoded.into_linear());
+    }
+
+    #[test]
+    fn index_channels() {
+        let mut color = Rgb::<Srgb>::new(0.1, 0.2, 0.3);
+        assert_eq!(color[0], 0.1);
+        assert_eq!(color[1], 0.2);
+        assert_eq!(color[2], 0.3);
+
+        color[2] = 0.8;
+        color[0] += 0.5;
+        assert_eq!(color, Rgb::new(0.6, 0.2, 0.8));
+
+        let mut transparent = Rgba::<Srgb, u8>::new(1, 2, 3, 4);
+        transparent[3] = 40;
+        assert_eq!(transparent, Rgba::new(1, 2, 3, 40));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let color = Rgb::<Srgb>::new(0.1, 0.2, 0.3);
+        let _ = color[3];
+    }
+
+    #[test]
+    fn clamp_to_legal_range() {
+        let legal = (16.0 / 255.0, 235.0 / 255.0);
+        let colors = [
+            Rgb::<Srgb>::new(0.0, 1.0, 0.5),
+            Rgb::new(-0.2, 0.05, 0.95),
+            Rgb::new(0.3, 0.4, 1.2),
+        ];
+
+        for &color in colors.iter() {
+            let clamped = color.clamp_to_range([legal; 3]);
+            for &component in [clamped.red, clamped.green, clamped.blue].iter() {
+                assert!(component >= legal.0 && component <= legal.1);
+            }
+        }
+
+        let clamped: Rgb<Srgb, u8> = Rgb::new(0u8, 128, 255).clamp_to_range([(16, 235); 3]);
+        assert_eq!(clamped, Rgb::new(16, 128, 235));
+
+        let unchanged = Rgb::<Srgb>::new(0.3, 0.4, 0.5).clamp_to_range([legal; 3]);
+        assert_eq!(unchanged, Rgb::new(0.3, 0.4, 0.5));
+    }
 
     #[test]
     fn ranges() {
@@ -1386,6 +1748,34 @@ mod test {
         assert_eq!(deserialized, Rgb::<Srgb>::new(0.3, 0.8, 0.1));
     }
 
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_round_trip() {
+        let color = Rgb::<Srgb>::new(0.3, 0.8, 0.1);
+        let vector = glam::Vec3::from(color);
+        assert_eq!(vector, glam::Vec3::new(0.3, 0.8, 0.1));
+        assert_eq!(Rgb::<Srgb>::from(vector), color);
+
+        let color = Rgba::<Srgb>::new(0.3, 0.8, 0.1, 0.5);
+        let vector = glam::Vec4::from(color);
+        assert_eq!(vector, glam::Vec4::new(0.3, 0.8, 0.1, 0.5));
+        assert_eq!(Rgba::<Srgb>::from(vector), color);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_round_trip() {
+        let color = Rgb::<Srgb>::new(0.3, 0.8, 0.1);
+        let vector = nalgebra::Vector3::from(color);
+        assert_eq!(vector, nalgebra::Vector3::new(0.3, 0.8, 0.1));
+        assert_eq!(Rgb::<Srgb>::from(vector), color);
+
+        let color = Rgba::<Srgb>::new(0.3, 0.8, 0.1, 0.5);
+        let vector = nalgebra::Vector4::from(color);
+        assert_eq!(vector, nalgebra::Vector4::new(0.3, 0.8, 0.1, 0.5));
+        assert_eq!(Rgba::<Srgb>::from(vector), color);
+    }
+
     #[test]
     fn from_str() {
         let c = Rgb::<Srgb, u8>::from_str("#ffffff");
@@ -1441,6 +1831,37 @@ mod test {
         assert_relative_eq!(Rgb::<Srgb, f32>::max_blue(), 1.0);
     }
 
+    #[test]
+    fn project_to_gamut_surface() {
+        use crate::{convert::FromColorUnclamped, Clamp, Oklab, Oklch};
+
+        let distance = |a: Rgb<Srgb, f32>, b: Rgb<Srgb, f32>| {
+            let a = Oklab::from_color_unclamped(a);
+            let b = Oklab::from_color_unclamped(b);
+            ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+        };
+
+        let on_surface = |color: Rgb<Srgb, f32>| {
+            let components = [color.red, color.green, color.blue];
+            components.iter().all(|&c| (0.0..=1.0).contains(&c))
+                && components.iter().any(|&c| c == 0.0 || c == 1.0)
+        };
+
+        let vivid = Rgb::<Srgb, f32>::from_color_unclamped(Oklch::new(0.7, 0.35, 150.0));
+        assert!(!on_surface(vivid));
+
+        let projected = vivid.project_to_gamut_surface();
+        assert!(on_surface(projected));
+
+        assert!(distance(projected, vivid) <= distance(vivid.clamp(), vivid));
+
+        // Colors inside the gamut are moved out to the surface.
+        let gray = Rgb::<Srgb, f32>::new(0.9, 0.9, 0.95);
+        let projected = gray.project_to_gamut_surface();
+        assert!(on_surface(projected));
+        assert!(distance(projected, gray) < 0.05);
+    }
+
     #[cfg(feature = "random")]
     test_uniform_distribution! {
         Rgb<Srgb, f32> {
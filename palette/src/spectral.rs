@@ -0,0 +1,246 @@
+//! Spectral representations of colors.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! A [`Spectrum`] describes how much light is reflected at each wavelength,
+//! which is what physically based renderers work with. The tristimulus values
+//! are calculated with an analytic fit of the CIE 1931 2° standard observer,
+//! by Wyman, Sloan and Shirley, in
+//! ["Simple Analytic Approximations to the CIE XYZ Color Matching Functions"](https://jcgt.org/published/0002/02/01/).
+
+use crate::{
+    matrix::matrix_inverse,
+    num::{Arithmetics, Real},
+    white_point::WhitePoint,
+    Xyz,
+};
+
+const SAMPLES: usize = 41;
+
+/// A reflectance spectrum, sampled at evenly spaced wavelengths.
+///
+/// The samples cover the visible range, from [`MIN_WAVELENGTH`] to
+/// [`MAX_WAVELENGTH`] nanometers, with [`WAVELENGTH_STEP`] nanometers between
+/// them. A sample value of `1.0` means that all light of that wavelength is
+/// reflected, while `0.0` means that all of it is absorbed.
+///
+/// The spectrum is evaluated under an equal energy illuminant and scaled to
+/// the white point of the resulting color, so a constant spectrum of `1.0`
+/// always gives the white point itself. This is an approximation of viewing
+/// the surface under the illuminant of that white point.
+///
+/// [`MIN_WAVELENGTH`]: Spectrum::MIN_WAVELENGTH
+/// [`MAX_WAVELENGTH`]: Spectrum::MAX_WAVELENGTH
+/// [`WAVELENGTH_STEP`]: Spectrum::WAVELENGTH_STEP
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spectrum<T = f32> {
+    /// The samples, from the shortest to the longest wavelength.
+    pub samples: [T; SAMPLES],
+}
+
+impl<T> Spectrum<T> {
+    /// The shortest sampled wavelength, in nanometers.
+    pub const MIN_WAVELENGTH: u32 = 380;
+
+    /// The longest sampled wavelength, in nanometers.
+    pub const MAX_WAVELENGTH: u32 = 780;
+
+    /// The distance between two samples, in nanometers.
+    pub const WAVELENGTH_STEP: u32 = 10;
+
+    /// The number of samples.
+    pub const SAMPLES: usize = SAMPLES;
+
+    /// Create a spectrum from its samples.
+    pub const fn new(samples: [T; SAMPLES]) -> Self {
+        Spectrum { samples }
+    }
+
+    /// Get the wavelength, in nanometers, of the sample at `index`.
+    pub fn wavelength(index: usize) -> u32 {
+        Self::MIN_WAVELENGTH + index as u32 * Self::WAVELENGTH_STEP
+    }
+}
+
+impl<T> Spectrum<T>
+where
+    T: Real + Arithmetics + Clone + Into<f64>,
+{
+    /// Calculate the color of the spectrum.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{spectral::Spectrum, white_point::D65, Xyz};
+    ///
+    /// let white: Xyz<D65, f64> = Spectrum::new([1.0; 41]).into_xyz();
+    /// assert_relative_eq!(white, Xyz::new(0.95047, 1.0, 1.08883), epsilon = 0.00001);
+    /// ```
+    pub fn into_xyz<Wp>(&self) -> Xyz<Wp, T>
+    where
+        Wp: WhitePoint<T>,
+    {
+        let [x, y, z] = integrate(|index| self.samples[index].clone().into());
+        let white = Wp::get_xyz();
+
+        Xyz::new(
+            white.x * T::from_f64(x),
+            white.y * T::from_f64(y),
+            white.z * T::from_f64(z),
+        )
+    }
+}
+
+/// Create a smooth reflectance spectrum that has the same color as `color`.
+///
+/// Many spectra can have the same color, so this picks a smooth one that is
+/// suitable as input for spectral rendering. The spectrum is a weighted sum of
+/// three broad basis functions, a constant and two cosine waves over the
+/// visible range, with the weights solved so that [`Spectrum::into_xyz`]
+/// gives back the original color. This means that white becomes a constant
+/// spectrum of `1.0`.
+///
+/// Very saturated colors, especially outside the sRGB gamut, may give
+/// samples outside the `[0.0, 1.0]` range, because a smooth spectrum can't
+/// reach them with physical reflectance values. Clamping the samples will
+/// make the spectrum physically plausible, at the cost of a less saturated
+/// color.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{spectral::upsample_to_spectrum, white_point::D65, FromColor, Srgb, Xyz};
+///
+/// let color = Xyz::from_color(Srgb::new(0.8f64, 0.5, 0.2));
+/// let spectrum = upsample_to_spectrum(color);
+///
+/// assert_relative_eq!(spectrum.into_xyz::<D65>(), color, epsilon = 0.000001);
+/// ```
+pub fn upsample_to_spectrum<Wp, T>(color: Xyz<Wp, T>) -> Spectrum<T>
+where
+    Wp: WhitePoint<T>,
+    T: Real + Arithmetics + Copy + Into<f64>,
+{
+    let white = Wp::get_xyz();
+    let target = [
+        (color.x / white.x).into(),
+        (color.y / white.y).into(),
+        (color.z / white.z).into(),
+    ];
+
+    // Each column is the tristimulus response of a basis function.
+    let mut responses = [0.0; 9];
+    for basis in 0..3 {
+        let response = integrate(|index| basis_function(basis, index));
+        for (channel, value) in response.iter().enumerate() {
+            responses[channel * 3 + basis] = *value;
+        }
+    }
+
+    let inverse = matrix_inverse(responses);
+    let mut weights = [0.0; 3];
+    for (basis, weight) in weights.iter_mut().enumerate() {
+        *weight = (0..3)
+            .map(|channel| inverse[basis * 3 + channel] * target[channel])
+            .sum();
+    }
+
+    let mut samples = [T::from_f64(0.0); SAMPLES];
+    for (index, sample) in samples.iter_mut().enumerate() {
+        let value: f64 = (0..3)
+            .map(|basis| weights[basis] * basis_function(basis, index))
+            .sum();
+        *sample = T::from_f64(value);
+    }
+
+    Spectrum::new(samples)
+}
+
+fn basis_function(basis: usize, index: usize) -> f64 {
+    let position = index as f64 / (SAMPLES - 1) as f64;
+    match basis {
+        0 => 1.0,
+        1 => (core::f64::consts::PI * position).cos(),
+        _ => (2.0 * core::f64::consts::PI * position).cos(),
+    }
+}
+
+/// Integrate a sampled spectrum against the color matching functions, with
+/// each channel normalized so that a constant `1.0` gives `1.0`.
+fn integrate(sample: impl Fn(usize) -> f64) -> [f64; 3] {
+    let mut sum = [0.0; 3];
+    let mut total = [0.0; 3];
+
+    for index in 0..SAMPLES {
+        let value = sample(index);
+        let responses = color_matching_functions(f64::from(Spectrum::<f64>::wavelength(index)));
+
+        for ((sum, total), response) in sum.iter_mut().zip(&mut total).zip(&responses) {
+            *sum += value * response;
+            *total += response;
+        }
+    }
+
+    [sum[0] / total[0], sum[1] / total[1], sum[2] / total[2]]
+}
+
+/// The multi-lobe fit of the CIE 1931 color matching functions, from Wyman,
+/// Sloan and Shirley.
+fn color_matching_functions(wavelength: f64) -> [f64; 3] {
+    fn lobe(wavelength: f64, mean: f64, below: f64, above: f64) -> f64 {
+        let deviation = if wavelength < mean { below } else { above };
+        let t = (wavelength - mean) / deviation;
+        (-0.5 * t * t).exp()
+    }
+
+    let x = 1.056 * lobe(wavelength, 599.8, 37.9, 31.0)
+        + 0.362 * lobe(wavelength, 442.0, 16.0, 26.7)
+        - 0.065 * lobe(wavelength, 501.1, 20.4, 26.2);
+    let y =
+        0.821 * lobe(wavelength, 568.8, 46.9, 40.5) + 0.286 * lobe(wavelength, 530.9, 16.3, 31.1);
+    let z =
+        1.217 * lobe(wavelength, 437.0, 11.8, 36.0) + 0.681 * lobe(wavelength, 459.0, 26.0, 13.8);
+
+    [x, y, z]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{upsample_to_spectrum, Spectrum};
+    use crate::{white_point::D65, FromColor, Srgb, Xyz};
+
+    #[test]
+    fn srgb_round_trip() {
+        let colors = [
+            Srgb::new(0.8f64, 0.5, 0.2),
+            Srgb::new(0.1, 0.6, 0.3),
+            Srgb::new(0.2, 0.3, 0.9),
+            Srgb::new(0.5, 0.5, 0.5),
+        ];
+
+        for &color in colors.iter() {
+            let spectrum = upsample_to_spectrum(Xyz::from_color(color));
+            let round_trip = Srgb::from_color(spectrum.into_xyz::<D65>());
+
+            assert_relative_eq!(round_trip, color, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn white_is_flat() {
+        let white: Xyz<D65, f64> = Xyz::from_color(Srgb::new(1.0, 1.0, 1.0));
+        let spectrum = upsample_to_spectrum(white);
+
+        for sample in spectrum.samples.iter() {
+            assert_relative_eq!(*sample, 1.0, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn wavelengths() {
+        assert_eq!(Spectrum::<f32>::wavelength(0), 380);
+        assert_eq!(
+            Spectrum::<f32>::wavelength(Spectrum::<f32>::SAMPLES - 1),
+            780
+        );
+    }
+}
@@ -0,0 +1,1531 @@
+//! Tools for analyzing collections of colors.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! The functions in this module operate on the raw components of the colors,
+//! as seen through [`ArrayCast`]. This means that the results depend on which
+//! color space the colors are in when they are passed to them, so make sure to
+//! convert them to a suitable space first.
+
+use core::cmp::Ordering;
+
+use crate::{
+    cast::{self, ArrayCast},
+    clamp,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding::linear::Linear,
+    num::{Arithmetics, IsValidDivisor, MinMax, One, Real, Sqrt, Zero},
+    rgb::{Rgb, RgbStandard},
+    white_point::D65,
+    Clamp, ColorDifference, FromColor, Lab, LinSrgb, Oklab, RelativeContrast, Srgb,
+};
+
+/// A three dimensional histogram of how a set of colors occupy their color
+/// space.
+///
+/// The space between a minimum and a maximum color is divided into a grid of
+/// equally sized bins, with [`bins_per_axis`](Histogram3d::bins_per_axis)
+/// bins along each component axis. The counts are stored as a flat list, where
+/// the first component varies the slowest and the last component varies the
+/// fastest. [`bin_index`](Histogram3d::bin_index) gives the position of a
+/// bin in that list.
+///
+/// See [`occupancy_3d`] for how to create one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Histogram3d {
+    bins_per_axis: usize,
+    counts: Vec<u32>,
+}
+
+impl Histogram3d {
+    /// The number of bins along each component axis.
+    pub fn bins_per_axis(&self) -> usize {
+        self.bins_per_axis
+    }
+
+    /// The number of colors in each bin, as a flat list of
+    /// `bins_per_axis³` values.
+    pub fn counts(&self) -> &[u32] {
+        &self.counts
+    }
+
+    /// Consume the histogram and return the flat list of counts.
+    pub fn into_counts(self) -> Vec<u32> {
+        self.counts
+    }
+
+    /// Get the position of the bin at `[first, second, third]` in the flat
+    /// list of counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bin coordinates are out of range.
+    pub fn bin_index(&self, [first, second, third]: [usize; 3]) -> usize {
+        let bins = self.bins_per_axis;
+        assert!(
+            first < bins && second < bins && third < bins,
+            "bin coordinates out of range"
+        );
+
+        (first * bins + second) * bins + third
+    }
+
+    /// Get the number of colors in the bin at `[first, second, third]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bin coordinates are out of range.
+    pub fn get(&self, bin: [usize; 3]) -> u32 {
+        self.counts[self.bin_index(bin)]
+    }
+
+    /// Count the number of bins that contain at least one color.
+    pub fn occupied_bins(&self) -> usize {
+        self.counts.iter().filter(|&&count| count > 0).count()
+    }
+}
+
+/// Count how many colors fall into each bin of a three dimensional grid.
+///
+/// The grid spans the range from `min` to `max`, with `bins_per_axis` bins
+/// along each axis. Components outside the range are counted in the nearest
+/// bin along their axis. This can, for example, be used for finding out how
+/// much of a gamut an image covers.
+///
+/// ```
+/// use palette::{stats::occupancy_3d, Srgb};
+///
+/// let pixels = [
+///     Srgb::new(0.1, 0.1, 0.1),
+///     Srgb::new(0.2, 0.1, 0.1),
+///     Srgb::new(0.9, 0.9, 0.9),
+/// ];
+/// let histogram = occupancy_3d(&pixels, 2, Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+///
+/// assert_eq!(histogram.get([0, 0, 0]), 2);
+/// assert_eq!(histogram.get([1, 1, 1]), 1);
+/// assert_eq!(histogram.occupied_bins(), 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bins_per_axis` is `0`.
+pub fn occupancy_3d<C, T>(pixels: &[C], bins_per_axis: usize, min: C, max: C) -> Histogram3d
+where
+    C: ArrayCast<Array = [T; 3]>,
+    T: Real + Arithmetics + PartialOrd + Clone + Into<f64>,
+{
+    assert!(bins_per_axis > 0, "there must be at least one bin per axis");
+
+    let min = cast::into_array(min);
+    let max = cast::into_array(max);
+    let mut histogram = Histogram3d {
+        bins_per_axis,
+        counts: vec![0; bins_per_axis * bins_per_axis * bins_per_axis],
+    };
+
+    for pixel in cast::into_array_slice(pixels) {
+        let index = histogram.bin_index(find_bin(pixel, &min, &max, bins_per_axis));
+        histogram.counts[index] += 1;
+    }
+
+    histogram
+}
+
+fn find_bin<T>(components: &[T; 3], min: &[T; 3], max: &[T; 3], bins_per_axis: usize) -> [usize; 3]
+where
+    T: Arithmetics + Clone + Into<f64>,
+{
+    let mut bin = [0; 3];
+
+    for ((bin, component), (min, max)) in bin.iter_mut().zip(components).zip(min.iter().zip(max)) {
+        let position: f64 =
+            ((component.clone() - min) / (max.clone() - min)).into() * bins_per_axis as f64;
+
+        // `as` saturates, which takes care of anything below the minimum
+        // and NaN values.
+        *bin = (position as usize).min(bins_per_axis - 1);
+    }
+
+    bin
+}
+
+/// Find the single most representative color in a set of colors.
+///
+/// The colors are sorted into a coarse grid in [`Oklab`], where each cell
+/// contains colors that are perceptually close to each other. The result is
+/// the average color of the most populated cell, which makes it suitable for
+/// picking an accent color from an image or a thumbnail. Returns `None` if
+/// `pixels` is empty.
+///
+/// ```
+/// use palette::{stats::dominant_color, Srgb};
+///
+/// let mut pixels = vec![Srgb::new(0.1f32, 0.2, 0.9); 10];
+/// pixels.push(Srgb::new(0.9, 0.9, 0.1));
+///
+/// let dominant = dominant_color(&pixels).unwrap();
+/// assert!(dominant.blue > dominant.red);
+/// ```
+pub fn dominant_color<C, T>(pixels: &[C]) -> Option<C>
+where
+    C: Clone + IntoColorUnclamped<Oklab<T>> + FromColorUnclamped<Oklab<T>>,
+    T: Real + Zero + Arithmetics + PartialOrd + Clone + Into<f64>,
+    Oklab<T>: Clamp,
+{
+    // Most displayable colors have `a` and `b` values within ±0.5, which
+    // makes each cell roughly 0.06 units wide.
+    const BINS_PER_AXIS: usize = 16;
+
+    if pixels.is_empty() {
+        return None;
+    }
+
+    let min = [T::zero(), T::from_f64(-0.5), T::from_f64(-0.5)];
+    let max = [T::from_f64(1.0), T::from_f64(0.5), T::from_f64(0.5)];
+
+    let colors: Vec<[T; 3]> = pixels
+        .iter()
+        .map(|pixel| cast::into_array(pixel.clone().into_color_unclamped()))
+        .collect();
+    let bins: Vec<[usize; 3]> = colors
+        .iter()
+        .map(|color| find_bin(color, &min, &max, BINS_PER_AXIS))
+        .collect();
+
+    let mut histogram = Histogram3d {
+        bins_per_axis: BINS_PER_AXIS,
+        counts: vec![0; BINS_PER_AXIS * BINS_PER_AXIS * BINS_PER_AXIS],
+    };
+    for &bin in &bins {
+        let index = histogram.bin_index(bin);
+        histogram.counts[index] += 1;
+    }
+
+    // The first bin wins if there's a tie, to keep the result stable.
+    let (dominant_index, &count) = histogram
+        .counts
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, count)| count)?;
+
+    let mut sum = Oklab::new(T::zero(), T::zero(), T::zero());
+    for (color, &bin) in colors.into_iter().zip(&bins) {
+        if histogram.bin_index(bin) == dominant_index {
+            sum = sum + cast::from_array::<Oklab<T>>(color);
+        }
+    }
+
+    let average = (sum / T::from_f64(count as f64)).clamp();
+    Some(C::from_color_unclamped(average))
+}
+
+/// Smooth out a sequence of colors with a moving average in [`Oklab`].
+///
+/// Each color is replaced by the average of itself and the `window / 2`
+/// colors on each side of it, which makes `window` the total width of the
+/// average when it's odd. The window is cut short at the ends of the
+/// sequence, instead of padding it with extra colors. A `window` of `0` or `1`
+/// leaves the colors unchanged.
+///
+/// The averages are converted back with [`FromColor`], so the results are
+/// clamped to the bounds of `C` even if the average ends up outside them.
+///
+/// ```
+/// use palette::{stats::smooth_colors, Srgb};
+///
+/// let colors = [
+///     Srgb::new(0.2f32, 0.2, 0.2),
+///     Srgb::new(0.9, 0.9, 0.9),
+///     Srgb::new(0.2, 0.2, 0.2),
+/// ];
+/// let smoothed = smooth_colors(&colors, 3);
+///
+/// assert!(smoothed[1].red < 0.9);
+/// assert!(smoothed[1].red > 0.2);
+/// ```
+pub fn smooth_colors<C, T>(colors: &[C], window: usize) -> Vec<C>
+where
+    C: Clone + IntoColorUnclamped<Oklab<T>> + FromColor<Oklab<T>>,
+    T: Real + Zero + Arithmetics + Clone,
+{
+    let half = window / 2;
+
+    // Running sums make each average cost the same, regardless of the window.
+    let mut sums = Vec::with_capacity(colors.len() + 1);
+    sums.push(Oklab::new(T::zero(), T::zero(), T::zero()));
+    for color in colors {
+        let sum = sums[sums.len() - 1].clone() + color.clone().into_color_unclamped();
+        sums.push(sum);
+    }
+
+    (0..colors.len())
+        .map(|index| {
+            let start = index.saturating_sub(half);
+            let end = (index + half + 1).min(colors.len());
+            let sum = sums[end].clone() - sums[start].clone();
+
+            C::from_color(sum / T::from_f64((end - start) as f64))
+        })
+        .collect()
+}
+
+/// Find the mean color of a set of noisy pixels, while ignoring outliers.
+///
+/// The mean is calculated in [`Oklab`], where the Euclidean distance is a
+/// perceptual color difference. Pixels that are more than `sigma` standard
+/// deviations away from the mean are rejected, and the mean is calculated
+/// again from the remaining pixels. This repeats until no more pixels are
+/// rejected. The standard deviation is the root mean square of the distances
+/// to the mean. Returns `None` if `pixels` is empty.
+///
+/// A `sigma` of about `2.0` to `3.0` keeps most of the noise, while rejecting
+/// colors that clearly don't belong, such as a small highlight on an
+/// otherwise even surface.
+///
+/// ```
+/// use palette::{stats::robust_mean, Srgb};
+///
+/// let mut pixels = vec![Srgb::new(0.5f32, 0.5, 0.5); 20];
+/// pixels.push(Srgb::new(1.0, 0.0, 0.0));
+///
+/// let mean: Srgb = robust_mean(&pixels, 2.0).unwrap();
+/// assert!((mean.red - mean.green).abs() < 0.001);
+/// ```
+pub fn robust_mean<C, T>(pixels: &[C], sigma: T) -> Option<C>
+where
+    C: Clone + IntoColorUnclamped<Oklab<T>> + FromColor<Oklab<T>>,
+    T: Real + Zero + Sqrt + Arithmetics + PartialOrd + Clone,
+{
+    let colors: Vec<Oklab<T>> = pixels
+        .iter()
+        .map(|pixel| pixel.clone().into_color_unclamped())
+        .collect();
+    let mut kept = vec![true; colors.len()];
+
+    let distance_squared = |a: &Oklab<T>, b: &Oklab<T>| {
+        let l = a.l.clone() - &b.l;
+        let a_difference = a.a.clone() - &b.a;
+        let b_difference = a.b.clone() - &b.b;
+        l.clone() * l + a_difference.clone() * a_difference + b_difference.clone() * b_difference
+    };
+
+    loop {
+        let mut sum = Oklab::new(T::zero(), T::zero(), T::zero());
+        let mut count = 0usize;
+        for (color, _) in colors.iter().zip(&kept).filter(|&(_, &kept)| kept) {
+            sum = sum + color.clone();
+            count += 1;
+        }
+
+        if count == 0 {
+            return None;
+        }
+
+        let count = T::from_f64(count as f64);
+        let mean = sum / count.clone();
+
+        let mut variance = T::zero();
+        for (color, _) in colors.iter().zip(&kept).filter(|&(_, &kept)| kept) {
+            variance = variance + distance_squared(color, &mean);
+        }
+        let limit = sigma.clone() * (variance / count).sqrt();
+        let limit_squared = limit.clone() * limit;
+
+        let mut rejected_any = false;
+        for (color, kept) in colors.iter().zip(&mut kept) {
+            if *kept && distance_squared(color, &mean) > limit_squared {
+                *kept = false;
+                rejected_any = true;
+            }
+        }
+
+        if !rejected_any {
+            return Some(C::from_color(mean));
+        }
+    }
+}
+
+/// Find the center of a set of pixels, where each pixel is weighted by the
+/// inverse of its color difference to the center.
+///
+/// The color difference is the Euclidean distance in [`Oklab`], which is a
+/// perceptual ΔE. Pixels that are far away from the center pull less on it
+/// than with a plain mean, so a few outliers don't move it away from the
+/// dense part of a noisy cluster. This is Weiszfeld's algorithm, which
+/// starts at the mean and approaches the geometric median. Returns `None` if
+/// `pixels` is empty.
+///
+/// Unlike [`robust_mean`], no pixel is rejected completely, so there's no
+/// threshold to tune.
+///
+/// ```
+/// use palette::{stats::delta_e_weighted_centroid, Srgb};
+///
+/// let mut pixels = vec![Srgb::new(0.5f32, 0.5, 0.5); 20];
+/// pixels.push(Srgb::new(1.0, 0.0, 0.0));
+///
+/// let center: Srgb = delta_e_weighted_centroid(&pixels).unwrap();
+/// assert!((center.red - center.green).abs() < 0.01);
+/// ```
+pub fn delta_e_weighted_centroid<C, T>(pixels: &[C]) -> Option<C>
+where
+    C: Clone + IntoColorUnclamped<Oklab<T>> + FromColor<Oklab<T>>,
+    T: Real + Zero + One + Sqrt + Arithmetics + PartialOrd + Clone,
+{
+    const MAX_ITERATIONS: usize = 100;
+
+    if pixels.is_empty() {
+        return None;
+    }
+
+    let colors: Vec<Oklab<T>> = pixels
+        .iter()
+        .map(|pixel| pixel.clone().into_color_unclamped())
+        .collect();
+
+    let distance = |a: &Oklab<T>, b: &Oklab<T>| {
+        let l = a.l.clone() - &b.l;
+        let a_difference = a.a.clone() - &b.a;
+        let b_difference = a.b.clone() - &b.b;
+        (l.clone() * l + a_difference.clone() * a_difference + b_difference.clone() * b_difference)
+            .sqrt()
+    };
+
+    let mut center = Oklab::new(T::zero(), T::zero(), T::zero());
+    for color in &colors {
+        center = center + color.clone();
+    }
+    center = center / T::from_f64(colors.len() as f64);
+
+    // Pixels this close to the center would get an infinite weight.
+    let min_distance = T::from_f64(1e-6);
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut sum = Oklab::new(T::zero(), T::zero(), T::zero());
+        let mut total_weight = T::zero();
+
+        for color in &colors {
+            let mut distance = distance(color, &center);
+            if distance < min_distance {
+                distance = min_distance.clone();
+            }
+
+            let weight = T::one() / distance;
+            sum = sum + color.clone() * weight.clone();
+            total_weight = total_weight + weight;
+        }
+
+        let next = sum / total_weight;
+        let moved = distance(&next, &center);
+        center = next;
+
+        if moved < min_distance {
+            break;
+        }
+    }
+
+    Some(C::from_color(center))
+}
+
+/// Find the direction along which a set of colors vary the most.
+///
+/// This is a principal component analysis of the components, as seen through
+/// [`ArrayCast`], which finds the axis with the largest variance. It can, for
+/// example, be used for deciding where to split a set of colors when reducing
+/// a palette, or for projecting colors onto a single dimension. The result is
+/// a unit length direction and the population variance along it, or `None`
+/// if `pixels` is empty.
+///
+/// The sign of the direction is chosen to make its largest component
+/// positive. The direction is arbitrary if all the colors are the same,
+/// since the variance is then `0` in every direction.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{stats::principal_axis, Oklab};
+///
+/// let pixels: Vec<Oklab> = (0..10)
+///     .map(|i| Oklab::new(0.5, i as f32 * 0.01, 0.0))
+///     .collect();
+/// let (direction, variance) = principal_axis(&pixels).unwrap();
+///
+/// assert_relative_eq!(direction[0], 0.0, epsilon = 0.0001);
+/// assert_relative_eq!(direction[1], 1.0, epsilon = 0.0001);
+/// assert_relative_eq!(direction[2], 0.0, epsilon = 0.0001);
+/// assert_relative_eq!(variance, 0.000825, epsilon = 0.000001);
+/// ```
+pub fn principal_axis<C, T>(pixels: &[C]) -> Option<([T; 3], T)>
+where
+    C: ArrayCast<Array = [T; 3]>,
+    T: Real + Clone + Into<f64>,
+{
+    const MAX_ITERATIONS: usize = 100;
+
+    if pixels.is_empty() {
+        return None;
+    }
+
+    let components = cast::into_array_slice(pixels);
+    let to_f64 = |color: &[T; 3]| -> [f64; 3] {
+        let [first, second, third] = color.clone();
+        [first.into(), second.into(), third.into()]
+    };
+
+    let count = pixels.len() as f64;
+    let mut mean = [0.0; 3];
+    for color in components {
+        for (mean, component) in mean.iter_mut().zip(&to_f64(color)) {
+            *mean += component / count;
+        }
+    }
+
+    let mut covariance = [[0.0; 3]; 3];
+    for color in components {
+        let color = to_f64(color);
+        let offset = [color[0] - mean[0], color[1] - mean[1], color[2] - mean[2]];
+
+        for (row, &row_offset) in covariance.iter_mut().zip(&offset) {
+            for (value, &column_offset) in row.iter_mut().zip(&offset) {
+                *value += row_offset * column_offset / count;
+            }
+        }
+    }
+
+    // Power iteration, starting from the covariance row with the largest
+    // magnitude to make sure it's not orthogonal to the principal axis.
+    let length = |vector: &[f64; 3]| vector.iter().map(|value| value * value).sum::<f64>().sqrt();
+    let mut direction = covariance
+        .iter()
+        .copied()
+        .max_by(|a, b| length(a).partial_cmp(&length(b)).unwrap_or(Ordering::Equal))
+        .expect("the covariance matrix has three rows");
+
+    if length(&direction) == 0.0 {
+        return Some((
+            [T::from_f64(1.0), T::from_f64(0.0), T::from_f64(0.0)],
+            T::from_f64(0.0),
+        ));
+    }
+
+    let mut variance = 0.0;
+    for _ in 0..MAX_ITERATIONS {
+        let vector_length = length(&direction);
+        let normalized = [
+            direction[0] / vector_length,
+            direction[1] / vector_length,
+            direction[2] / vector_length,
+        ];
+        let mut next = [0.0; 3];
+        for (next, row) in next.iter_mut().zip(&covariance) {
+            *next = row
+                .iter()
+                .zip(&normalized)
+                .map(|(value, component)| value * component)
+                .sum::<f64>();
+        }
+
+        let previous = variance;
+        variance = length(&next);
+        direction = normalized;
+
+        if variance == 0.0 || (variance - previous).abs() <= variance * 1e-12 {
+            break;
+        }
+
+        direction = next;
+    }
+
+    let vector_length = length(&direction);
+    let largest = direction
+        .iter()
+        .copied()
+        .max_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap_or(Ordering::Equal))
+        .expect("the direction has three components");
+    let scale = if largest < 0.0 {
+        -vector_length
+    } else {
+        vector_length
+    };
+
+    Some((
+        [
+            T::from_f64(direction[0] / scale),
+            T::from_f64(direction[1] / scale),
+            T::from_f64(direction[2] / scale),
+        ],
+        T::from_f64(variance),
+    ))
+}
+
+/// Estimate the color of the light in an image, using the gray world
+/// assumption.
+///
+/// The gray world assumption is that the average color of a typical scene is
+/// a neutral gray, so any tint in the average must come from the light. The
+/// estimate is the average of the pixels in linear RGB, scaled so that its
+/// largest component is `1.0`. A neutral estimate, like for an empty set of
+/// pixels, is white.
+///
+/// Pass the estimate to [`white_balance`] to remove the tint.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{stats::{auto_white_balance, white_balance}, LinSrgb, Srgb};
+///
+/// // A gray image under a warm light.
+/// let mut pixels: Vec<Srgb> = (1..10)
+///     .map(|i| {
+///         let gray = i as f32 / 10.0;
+///         Srgb::from_linear(LinSrgb::new(gray, gray * 0.9, gray * 0.7))
+///     })
+///     .collect();
+///
+/// let illuminant = auto_white_balance(&pixels);
+/// assert_relative_eq!(illuminant.into_linear(), LinSrgb::new(1.0, 0.9, 0.7), epsilon = 0.0001);
+///
+/// white_balance(&mut pixels, illuminant);
+/// for pixel in pixels {
+///     assert_relative_eq!(pixel.red, pixel.blue, epsilon = 0.0001);
+/// }
+/// ```
+pub fn auto_white_balance<S, T>(pixels: &[Rgb<S, T>]) -> Rgb<S, T>
+where
+    S: RgbStandard<T>,
+    T: Real + Zero + One + Arithmetics + MinMax + IsValidDivisor + Clone,
+{
+    let mut sum = Rgb::<Linear<S::Space>, T>::new(T::zero(), T::zero(), T::zero());
+    for pixel in pixels {
+        sum = sum + pixel.clone().into_linear();
+    }
+
+    let max = sum.red.clone().max(sum.green.clone()).max(sum.blue.clone());
+    if !max.is_valid_divisor() {
+        return Rgb::new(T::one(), T::one(), T::one());
+    }
+
+    Rgb::from_linear(sum / max)
+}
+
+/// Remove the tint of an `illuminant` from a set of pixels, using a simple
+/// per-channel scaling in linear RGB.
+///
+/// The illuminant is typically estimated by [`auto_white_balance`]. The
+/// channels are scaled so that the illuminant itself would become a gray with
+/// the same average component value, which keeps the overall brightness about
+/// the same. The results are not clamped, so some pixels may end up outside
+/// the `[0.0, 1.0]` range. Channels where the illuminant is `0.0` are left as
+/// they are.
+pub fn white_balance<S, T>(pixels: &mut [Rgb<S, T>], illuminant: Rgb<S, T>)
+where
+    S: RgbStandard<T>,
+    T: Real + One + Arithmetics + IsValidDivisor + Clone,
+{
+    let illuminant = illuminant.into_linear();
+    let gray = (illuminant.red.clone() + &illuminant.green + &illuminant.blue) / T::from_f64(3.0);
+    let gain = |component: T| {
+        if component.is_valid_divisor() {
+            gray.clone() / component
+        } else {
+            T::one()
+        }
+    };
+    let gains = Rgb::<Linear<S::Space>, T>::new(
+        gain(illuminant.red),
+        gain(illuminant.green),
+        gain(illuminant.blue),
+    );
+
+    for pixel in pixels {
+        let linear = pixel.clone().into_linear() * gains.clone();
+        *pixel = Rgb::from_linear(linear);
+    }
+}
+
+/// Measure how colorful a single color is.
+///
+/// This is the same metric as [`colorfulness_of_image`], for an image where
+/// every pixel is `color`, and has the same scale. It's `0` for any shade of
+/// gray.
+///
+/// ```
+/// use palette::{stats::colorfulness, Srgb};
+///
+/// assert_eq!(colorfulness(Srgb::new(0.5, 0.5, 0.5)), 0.0);
+/// assert!(colorfulness(Srgb::new(1.0, 0.0, 0.0)) > 50.0);
+/// ```
+pub fn colorfulness<C>(color: C) -> f32
+where
+    C: IntoColorUnclamped<Srgb<f32>>,
+{
+    let (red_green, yellow_blue) = opponent_components(color);
+    0.3 * (red_green * red_green + yellow_blue * yellow_blue).sqrt()
+}
+
+/// Measure how colorful an image is, for example for ranking images.
+///
+/// This is the metric by Hasler and Süsstrunk, which combines the standard
+/// deviation and the mean of two opponent color components, calculated from
+/// the non-linear sRGB components. The sRGB components are scaled to
+/// `0..=255`, to have the same scale as the original paper, where about `15`
+/// is slightly colorful, about `59` is quite colorful and above `109` is
+/// extremely colorful. An empty image has a colorfulness of `0`.
+///
+/// ```
+/// use palette::{stats::colorfulness_of_image, Srgb};
+///
+/// let gray = vec![Srgb::new(0.3, 0.3, 0.3), Srgb::new(0.8, 0.8, 0.8)];
+/// let vivid = vec![Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 0.0, 1.0)];
+///
+/// assert!(colorfulness_of_image(&gray) < 0.001);
+/// assert!(colorfulness_of_image(&vivid) > 100.0);
+/// ```
+pub fn colorfulness_of_image<C>(pixels: &[C]) -> f32
+where
+    C: Clone + IntoColorUnclamped<Srgb<f32>>,
+{
+    if pixels.is_empty() {
+        return 0.0;
+    }
+
+    let count = pixels.len() as f32;
+    let opponents: Vec<(f32, f32)> = pixels
+        .iter()
+        .map(|pixel| opponent_components(pixel.clone()))
+        .collect();
+
+    let (sum_red_green, sum_yellow_blue) = opponents
+        .iter()
+        .fold((0.0, 0.0), |(rg, yb), &(red_green, yellow_blue)| {
+            (rg + red_green, yb + yellow_blue)
+        });
+    let mean_red_green = sum_red_green / count;
+    let mean_yellow_blue = sum_yellow_blue / count;
+
+    let variance = opponents
+        .iter()
+        .map(|&(red_green, yellow_blue)| {
+            let red_green = red_green - mean_red_green;
+            let yellow_blue = yellow_blue - mean_yellow_blue;
+            red_green * red_green + yellow_blue * yellow_blue
+        })
+        .sum::<f32>()
+        / count;
+
+    variance.sqrt()
+        + 0.3 * (mean_red_green * mean_red_green + mean_yellow_blue * mean_yellow_blue).sqrt()
+}
+
+/// The red-green and yellow-blue opponent components of a color, scaled to
+/// the 8 bit range.
+fn opponent_components<C>(color: C) -> (f32, f32)
+where
+    C: IntoColorUnclamped<Srgb<f32>>,
+{
+    let color: Srgb<f32> = color.into_color_unclamped();
+    let (red, green, blue) = (color.red * 255.0, color.green * 255.0, color.blue * 255.0);
+
+    (red - green, 0.5 * (red + green) - blue)
+}
+
+/// Replace each pixel with the index of the closest color in `palette`, and
+/// measure how much the colors changed.
+///
+/// The closest color is the one with the smallest CIEDE2000 color difference,
+/// measured in [`Lab`] with the D65 white point. The second value in the
+/// result is the mean color difference between the pixels and their palette
+/// colors, which can be used for judging the quality of the palette. A mean
+/// difference below about 2.3 is hard to notice. It's `0` if `pixels` is
+/// empty.
+///
+/// ```
+/// use palette::{stats::quantize_with_error, Srgb};
+///
+/// let palette = [Srgb::new(0.0f32, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0)];
+/// let pixels = [Srgb::new(0.1, 0.1, 0.1), Srgb::new(0.9, 0.95, 1.0)];
+///
+/// let (indices, mean_error) = quantize_with_error(&pixels, &palette);
+/// assert_eq!(indices, [0, 1]);
+/// assert!(mean_error > 0.0);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `palette` is empty or has more than 256 colors.
+pub fn quantize_with_error<C>(pixels: &[C], palette: &[C]) -> (Vec<u8>, f32)
+where
+    C: Clone + IntoColorUnclamped<Lab<D65, f32>>,
+{
+    let palette = palette_to_lab(palette);
+    let assignments: Vec<(u8, f32)> = pixels
+        .iter()
+        .map(|pixel| closest_in_palette(pixel.clone().into_color_unclamped(), &palette))
+        .collect();
+
+    split_assignments(assignments)
+}
+
+/// Replace each pixel with the index of the closest color in `palette`, and
+/// measure how much the colors changed, with the pixels split between
+/// multiple threads.
+///
+/// This is the same as [`quantize_with_error`], but the closest color is
+/// found in parallel, using [`rayon`](https://crates.io/crates/rayon). The
+/// result is always the same as from [`quantize_with_error`], since the errors
+/// are added up in the same order.
+///
+/// This function is only available if the `rayon` feature is enabled.
+///
+/// ## Panics
+///
+/// Panics if `palette` is empty or has more than 256 colors.
+#[cfg(feature = "rayon")]
+pub fn par_quantize_with_error<C>(pixels: &[C], palette: &[C]) -> (Vec<u8>, f32)
+where
+    C: Clone + Sync + IntoColorUnclamped<Lab<D65, f32>>,
+{
+    use rayon_crate::prelude::*;
+
+    let palette = palette_to_lab(palette);
+    let assignments: Vec<(u8, f32)> = pixels
+        .par_iter()
+        .map(|pixel| closest_in_palette(pixel.clone().into_color_unclamped(), &palette))
+        .collect();
+
+    split_assignments(assignments)
+}
+
+fn palette_to_lab<C>(palette: &[C]) -> Vec<Lab<D65, f32>>
+where
+    C: Clone + IntoColorUnclamped<Lab<D65, f32>>,
+{
+    assert!(
+        !palette.is_empty() && palette.len() <= 256,
+        "the palette must have between 1 and 256 colors"
+    );
+
+    palette
+        .iter()
+        .map(|color| color.clone().into_color_unclamped())
+        .collect()
+}
+
+/// Find the index of the closest palette color, and its color difference.
+fn closest_in_palette(pixel: Lab<D65, f32>, palette: &[Lab<D65, f32>]) -> (u8, f32) {
+    let mut closest = 0;
+    let mut closest_difference = f32::INFINITY;
+    for (index, &color) in palette.iter().enumerate() {
+        let difference = pixel.get_color_difference(color);
+        if difference < closest_difference {
+            closest = index;
+            closest_difference = difference;
+        }
+    }
+
+    (closest as u8, closest_difference)
+}
+
+/// Split the assignments into indices and the mean error, adding the errors up
+/// in order to make the result deterministic.
+fn split_assignments(assignments: Vec<(u8, f32)>) -> (Vec<u8>, f32) {
+    let mut total_error = 0.0;
+    let indices = assignments
+        .iter()
+        .map(|&(index, error)| {
+            total_error += error;
+            index
+        })
+        .collect();
+
+    let mean_error = if assignments.is_empty() {
+        0.0
+    } else {
+        total_error / assignments.len() as f32
+    };
+
+    (indices, mean_error)
+}
+
+/// Find the black and white points that stretch the luma of an image to the
+/// full range, for automatic levels adjustment.
+///
+/// The luma of each pixel is the Rec. 709 weighted sum of its non-linear
+/// components, and it's sorted into a histogram with 256 bins. The black
+/// point is where `clip_percent` percent of the pixels are darker, and the
+/// white point is where `clip_percent` percent of the pixels are brighter.
+/// Clipping a small part, such as `0.5` percent, keeps a few stray pixels from
+/// deciding the result. An empty image gives `(0.0, 1.0)`.
+///
+/// Pass the points to [`apply_levels`] to stretch the image.
+///
+/// ```
+/// use palette::{stats::{apply_levels, auto_contrast}, Srgb};
+///
+/// let mut pixels: Vec<Srgb> = (0..100)
+///     .map(|i| {
+///         let value = 0.3 + i as f32 * 0.004;
+///         Srgb::new(value, value, value)
+///     })
+///     .collect();
+///
+/// let (black, white) = auto_contrast(&pixels, 0.0);
+/// assert!((black - 0.3).abs() < 0.01 && (white - 0.7).abs() < 0.01);
+///
+/// apply_levels(&mut pixels, black, white);
+/// assert!(pixels[0].red < 0.01 && pixels[99].red > 0.99);
+/// ```
+pub fn auto_contrast(pixels: &[Srgb<f32>], clip_percent: f32) -> (f32, f32) {
+    const BINS: usize = 256;
+
+    if pixels.is_empty() {
+        return (0.0, 1.0);
+    }
+
+    let mut histogram = [0usize; BINS];
+    for pixel in pixels {
+        let luma = 0.2126 * pixel.red + 0.7152 * pixel.green + 0.0722 * pixel.blue;
+        // `as` saturates, which takes care of anything below 0 and NaN values.
+        let bin = ((luma * BINS as f32) as usize).min(BINS - 1);
+        histogram[bin] += 1;
+    }
+
+    let clipped = (pixels.len() as f32 * clamp(clip_percent, 0.0, 100.0) / 100.0) as usize;
+
+    let find_bin = |bins: &mut dyn Iterator<Item = (usize, &usize)>| {
+        let mut count = 0;
+        for (bin, &bin_count) in bins {
+            count += bin_count;
+            if count > clipped {
+                return bin;
+            }
+        }
+        0
+    };
+    let black = find_bin(&mut histogram.iter().enumerate());
+    let white = find_bin(&mut histogram.iter().enumerate().rev());
+
+    (black as f32 / BINS as f32, (white + 1) as f32 / BINS as f32)
+}
+
+/// Stretch the components of each pixel, so `black` becomes `0` and `white`
+/// becomes `1`.
+///
+/// The components are clamped to the range `0.0..=1.0` after the adjustment.
+/// The pixels are left unchanged if `white` isn't greater than `black`. See
+/// [`auto_contrast`] for finding the points automatically.
+pub fn apply_levels(pixels: &mut [Srgb<f32>], black: f32, white: f32) {
+    if white <= black {
+        return;
+    }
+
+    let scale = 1.0 / (white - black);
+    let adjust = |component: f32| clamp((component - black) * scale, 0.0, 1.0);
+
+    for pixel in pixels {
+        *pixel = Srgb::new(adjust(pixel.red), adjust(pixel.green), adjust(pixel.blue));
+    }
+}
+
+/// Keep the colors in `candidates` that have a contrast ratio of at least
+/// `ratio` against `background`.
+///
+/// The contrast ratio is the one from the WCAG guidelines, as calculated by
+/// [`RelativeContrast`]. This is useful for building accessible palettes,
+/// where for example a `ratio` of `4.5` keeps the colors that can be used for
+/// normal text on the background. The order of the colors is kept.
+///
+/// ```
+/// use palette::{stats::filter_by_contrast, Srgb};
+///
+/// let background = Srgb::new(1.0f32, 1.0, 1.0);
+/// let candidates = [
+///     Srgb::new(0.1, 0.1, 0.1),
+///     Srgb::new(0.9, 0.9, 0.2),
+///     Srgb::new(0.0, 0.2, 0.6),
+/// ];
+///
+/// let readable = filter_by_contrast(&candidates, background, 4.5);
+/// assert_eq!(readable, [candidates[0], candidates[2]]);
+/// ```
+pub fn filter_by_contrast<C>(candidates: &[C], background: C, ratio: C::Scalar) -> Vec<C>
+where
+    C: RelativeContrast + Clone,
+{
+    candidates
+        .iter()
+        .filter(|&candidate| candidate.clone().get_contrast_ratio(background.clone()) >= ratio)
+        .cloned()
+        .collect()
+}
+
+/// List the entries that are different in `b`, compared to `a`, as pairs of
+/// indices and new colors.
+///
+/// This is useful for palette animation, where only the changed entries have
+/// to be uploaded each frame. Entries that are only in `b` count as changed,
+/// while entries that are only in `a` are left out. Use
+/// [`apply_palette_diff`] to apply the changes to `a`.
+///
+/// ```
+/// use palette::{stats::palette_diff, Srgb};
+///
+/// let a = [Srgb::new(0u8, 0, 0), Srgb::new(255, 0, 0), Srgb::new(0, 0, 255)];
+/// let b = [Srgb::new(0u8, 0, 0), Srgb::new(0, 255, 0), Srgb::new(0, 0, 255)];
+///
+/// assert_eq!(palette_diff(&a, &b), [(1, Srgb::new(0, 255, 0))]);
+/// ```
+pub fn palette_diff<C>(a: &[C], b: &[C]) -> Vec<(usize, C)>
+where
+    C: PartialEq + Clone,
+{
+    b.iter()
+        .enumerate()
+        .filter(|&(index, color)| a.get(index) != Some(color))
+        .map(|(index, color)| (index, color.clone()))
+        .collect()
+}
+
+/// Apply the changes from [`palette_diff`] to `palette`.
+///
+/// ## Panics
+///
+/// Panics if an index in `diff` is out of bounds for `palette`.
+pub fn apply_palette_diff<C>(palette: &mut [C], diff: &[(usize, C)])
+where
+    C: Clone,
+{
+    for (index, color) in diff {
+        palette[*index] = color.clone();
+    }
+}
+
+/// How [`ColorMean`] averages its colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeanKind {
+    /// The arithmetic mean of the components in [`Oklab`]. This is the
+    /// perceptual average of the colors.
+    Arithmetic,
+    /// The geometric mean of the linear light sRGB components. This is what
+    /// averaging exposures or transmittances calls for, and it's pulled
+    /// towards the darker colors. A component that is `0.0`, or below, in any
+    /// of the colors makes that component `0.0` in the mean.
+    Geometric,
+}
+
+/// An accumulator for the weighted mean of a set of colors.
+///
+/// Colors are added one at a time, with [`add`](ColorMean::add) or
+/// [`add_weighted`](ColorMean::add_weighted), and the mean can be read at any
+/// point with [`mean`](ColorMean::mean). The sums are kept as `f64`, to keep
+/// the rounding errors small when there are many colors.
+///
+/// ```
+/// use palette::{stats::{ColorMean, MeanKind}, Srgb};
+///
+/// let mut arithmetic = ColorMean::new(MeanKind::Arithmetic);
+/// let mut geometric = ColorMean::new(MeanKind::Geometric);
+///
+/// for &color in [Srgb::new(0.1f32, 0.1, 0.1), Srgb::new(0.9, 0.9, 0.9)].iter() {
+///     arithmetic.add(color);
+///     geometric.add(color);
+/// }
+///
+/// let arithmetic: Srgb = arithmetic.mean().unwrap();
+/// let geometric: Srgb = geometric.mean().unwrap();
+/// assert!(geometric.red < arithmetic.red);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorMean {
+    kind: MeanKind,
+    sums: [f64; 3],
+    weight: f64,
+}
+
+impl ColorMean {
+    /// Create an empty accumulator for the `kind` of mean.
+    pub fn new(kind: MeanKind) -> Self {
+        ColorMean {
+            kind,
+            sums: [0.0; 3],
+            weight: 0.0,
+        }
+    }
+
+    /// Add a color, with a weight of `1.0`.
+    pub fn add<C, T>(&mut self, color: C)
+    where
+        C: IntoColorUnclamped<Oklab<T>>,
+        T: Into<f64>,
+    {
+        self.add_weighted(color, 1.0);
+    }
+
+    /// Add a color, with a `weight` that decides how much it counts. Colors
+    /// with a weight of `0.0` or less, or a weight that is not finite, are
+    /// ignored.
+    pub fn add_weighted<C, T>(&mut self, color: C, weight: f64)
+    where
+        C: IntoColorUnclamped<Oklab<T>>,
+        T: Into<f64>,
+    {
+        if !(weight > 0.0 && weight.is_finite()) {
+            return;
+        }
+
+        let Oklab { l, a, b } = color.into_color_unclamped();
+        let oklab = Oklab::new(l.into(), a.into(), b.into());
+
+        let components = match self.kind {
+            MeanKind::Arithmetic => [oklab.l, oklab.a, oklab.b],
+            MeanKind::Geometric => {
+                let linear = LinSrgb::<f64>::from_color_unclamped(oklab);
+                [
+                    linear.red.max(0.0).ln(),
+                    linear.green.max(0.0).ln(),
+                    linear.blue.max(0.0).ln(),
+                ]
+            }
+        };
+
+        for (sum, component) in self.sums.iter_mut().zip(&components) {
+            *sum += component * weight;
+        }
+        self.weight += weight;
+    }
+
+    /// Get the mean of the colors that have been added so far, or `None` if
+    /// no colors have been added.
+    pub fn mean<C, T>(&self) -> Option<C>
+    where
+        C: FromColorUnclamped<Oklab<T>>,
+        T: Real,
+    {
+        if self.weight <= 0.0 {
+            return None;
+        }
+
+        let first = self.sums[0] / self.weight;
+        let second = self.sums[1] / self.weight;
+        let third = self.sums[2] / self.weight;
+        let oklab = match self.kind {
+            MeanKind::Arithmetic => Oklab::new(first, second, third),
+            MeanKind::Geometric => {
+                Oklab::from_color_unclamped(LinSrgb::new(first.exp(), second.exp(), third.exp()))
+            }
+        };
+
+        Some(C::from_color_unclamped(Oklab::new(
+            T::from_f64(oklab.l),
+            T::from_f64(oklab.a),
+            T::from_f64(oklab.b),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        apply_levels, apply_palette_diff, auto_contrast, auto_white_balance, colorfulness,
+        colorfulness_of_image, delta_e_weighted_centroid, dominant_color, filter_by_contrast,
+        occupancy_3d, palette_diff, principal_axis, quantize_with_error, robust_mean,
+        smooth_colors, white_balance, ColorMean, MeanKind,
+    };
+    use crate::{white_point::D65, IsWithinBounds, Lab, LinSrgb, Oklab, RelativeContrast, Srgb};
+
+    #[test]
+    fn single_color_occupancy() {
+        let pixels = vec![Srgb::new(0.3, 0.6, 0.9); 100];
+        let histogram = occupancy_3d(
+            &pixels,
+            4,
+            Srgb::new(0.0, 0.0, 0.0),
+            Srgb::new(1.0, 1.0, 1.0),
+        );
+
+        assert_eq!(histogram.counts().len(), 64);
+        assert_eq!(histogram.occupied_bins(), 1);
+        assert_eq!(histogram.get([1, 2, 3]), 100);
+        assert_eq!(histogram.counts().iter().sum::<u32>(), 100);
+    }
+
+    #[test]
+    fn out_of_range_occupancy() {
+        let pixels = [Oklab::new(-0.5, 2.0, 0.0), Oklab::new(1.0, 1.0, 1.0)];
+        let histogram = occupancy_3d(
+            &pixels,
+            3,
+            Oklab::new(0.0, -1.0, -1.0),
+            Oklab::new(1.0, 1.0, 1.0),
+        );
+
+        assert_eq!(histogram.get([0, 2, 1]), 1);
+        assert_eq!(histogram.get([2, 2, 2]), 1);
+    }
+
+    #[test]
+    fn dominant_color_mostly_blue() {
+        let mut pixels: Vec<Srgb> = Vec::new();
+        for i in 0..50 {
+            let offset = (i % 5) as f32 * 0.01;
+            pixels.push(Srgb::new(0.1 + offset, 0.2, 0.85 - offset));
+        }
+        pixels.extend(vec![Srgb::new(0.9, 0.1, 0.1); 10]);
+        pixels.extend(vec![Srgb::new(0.1, 0.8, 0.1); 10]);
+
+        let dominant = dominant_color(&pixels).unwrap();
+        assert!(dominant.blue > 0.7);
+        assert!(dominant.red < 0.3);
+        assert!(dominant.green < 0.3);
+    }
+
+    #[test]
+    fn dominant_color_empty() {
+        assert_eq!(dominant_color::<Srgb, f32>(&[]), None);
+    }
+
+    #[test]
+    fn bin_index() {
+        let histogram =
+            occupancy_3d::<Srgb, _>(&[], 3, Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+
+        assert_eq!(histogram.bin_index([0, 0, 0]), 0);
+        assert_eq!(histogram.bin_index([0, 0, 1]), 1);
+        assert_eq!(histogram.bin_index([0, 1, 0]), 3);
+        assert_eq!(histogram.bin_index([1, 0, 0]), 9);
+        assert_eq!(histogram.bin_index([2, 2, 2]), 26);
+    }
+
+    #[test]
+    fn gray_world_tinted_gray() {
+        let tint = LinSrgb::new(0.8, 1.0, 0.6);
+        let mut pixels: Vec<Srgb> = (0..=20)
+            .map(|i| {
+                let gray = i as f32 / 20.0;
+                Srgb::from_linear(tint * gray)
+            })
+            .collect();
+
+        let illuminant = auto_white_balance(&pixels);
+        assert_relative_eq!(illuminant.into_linear(), tint, epsilon = 0.0001);
+
+        white_balance(&mut pixels, illuminant);
+        let corrected = auto_white_balance(&pixels).into_linear();
+        assert_relative_eq!(corrected, LinSrgb::new(1.0, 1.0, 1.0), epsilon = 0.0001);
+
+        for pixel in pixels {
+            assert_relative_eq!(pixel.red, pixel.green, epsilon = 0.0001);
+            assert_relative_eq!(pixel.green, pixel.blue, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn gray_world_empty() {
+        let illuminant = auto_white_balance::<_, f32>(&[] as &[Srgb]);
+        assert_relative_eq!(illuminant, Srgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn smooth_spiky_sequence() {
+        let mut colors = vec![Srgb::new(0.1f32, 0.1, 0.9); 20];
+        colors[5] = Srgb::new(1.0, 0.0, 0.0);
+        colors[12] = Srgb::new(0.0, 1.0, 0.0);
+
+        let smoothed = smooth_colors(&colors, 5);
+        assert_eq!(smoothed.len(), colors.len());
+
+        for color in &smoothed {
+            assert!(color.is_within_bounds());
+        }
+
+        // The spikes are spread out and toned down.
+        assert!(smoothed[5].red < 0.9);
+        assert!(smoothed[4].red > colors[4].red);
+        assert!(smoothed[12].green < 0.9);
+        assert!(smoothed[13].green > colors[13].green);
+
+        // Colors that are far from the spikes stay the same.
+        assert_relative_eq!(smoothed[0], colors[0], epsilon = 0.0001);
+        assert_relative_eq!(smoothed[19], colors[19], epsilon = 0.0001);
+    }
+
+    #[test]
+    fn smooth_small_windows() {
+        let colors = [Srgb::new(0.1f32, 0.5, 0.9), Srgb::new(0.9, 0.5, 0.1)];
+
+        for window in 0..2 {
+            let smoothed = smooth_colors(&colors, window);
+            assert_relative_eq!(smoothed[0], colors[0], epsilon = 0.0001);
+            assert_relative_eq!(smoothed[1], colors[1], epsilon = 0.0001);
+        }
+
+        assert!(smooth_colors::<Srgb, f32>(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn principal_axis_of_line() {
+        let direction = [2.0f64 / 7.0, 3.0 / 7.0, -6.0 / 7.0];
+        let pixels: Vec<Srgb<f64>> = (0..=20)
+            .map(|i| {
+                let t = i as f64 / 20.0 - 0.5;
+                Srgb::new(
+                    0.5 + t * direction[0] * 0.5,
+                    0.4 + t * direction[1] * 0.5,
+                    0.6 + t * direction[2] * 0.5,
+                )
+            })
+            .collect();
+
+        let (axis, variance) = principal_axis(&pixels).unwrap();
+
+        // The largest component is negative, so the sign is flipped.
+        assert_relative_eq!(axis[0], -direction[0], epsilon = 0.000001);
+        assert_relative_eq!(axis[1], -direction[1], epsilon = 0.000001);
+        assert_relative_eq!(axis[2], -direction[2], epsilon = 0.000001);
+        assert!(variance > 0.0);
+    }
+
+    #[test]
+    fn principal_axis_edge_cases() {
+        assert_eq!(principal_axis::<Srgb, f32>(&[]), None);
+
+        let same = vec![Srgb::new(0.2f32, 0.3, 0.4); 5];
+        assert_eq!(principal_axis(&same), Some(([1.0, 0.0, 0.0], 0.0)));
+    }
+
+    #[test]
+    fn geometric_and_arithmetic_means() {
+        let dark = LinSrgb::new(0.01f32, 0.02, 0.04);
+        let light = LinSrgb::new(1.0f32, 0.9, 0.8);
+
+        let mean = |kind, colors: &[LinSrgb]| {
+            let mut mean = ColorMean::new(kind);
+            for &color in colors {
+                mean.add(color);
+            }
+            mean.mean::<LinSrgb, f32>().unwrap()
+        };
+
+        let arithmetic = mean(MeanKind::Arithmetic, &[dark, light]);
+        let geometric = mean(MeanKind::Geometric, &[dark, light]);
+        assert_relative_eq!(
+            geometric,
+            LinSrgb::new(0.1, 0.134164, 0.178885),
+            epsilon = 0.0001
+        );
+        assert!(arithmetic.red > geometric.red + 0.05);
+
+        for &kind in &[MeanKind::Arithmetic, MeanKind::Geometric] {
+            assert_relative_eq!(mean(kind, &[light, light, light]), light, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let mut mean = ColorMean::new(MeanKind::Arithmetic);
+        assert_eq!(mean.mean::<Oklab, f32>(), None);
+
+        mean.add_weighted(Oklab::new(0.2f32, 0.0, 0.1), 3.0);
+        mean.add_weighted(Oklab::new(0.6f32, 0.1, 0.0), 1.0);
+        mean.add_weighted(Oklab::new(1.0f32, 0.0, 0.0), 0.0);
+        mean.add_weighted(Oklab::new(1.0f32, 0.0, 0.0), f64::NAN);
+
+        let result: Oklab = mean.mean().unwrap();
+        assert_relative_eq!(result, Oklab::new(0.3, 0.025, 0.075), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn delta_e_weighted_centroid_near_dense_cluster() {
+        let mut pixels: Vec<Oklab<f64>> = (0..30)
+            .map(|i| {
+                let offset = (i % 5) as f64 * 0.004 - 0.008;
+                Oklab::new(0.6 + offset, 0.05 - offset, 0.02)
+            })
+            .collect();
+        pixels.extend(vec![Oklab::new(0.2, -0.15, -0.1); 3]);
+        pixels.push(Oklab::new(0.9, 0.2, 0.15));
+
+        let dense = Oklab::new(0.6, 0.05, 0.02);
+        let centroid = delta_e_weighted_centroid(&pixels).unwrap();
+        let mean = pixels
+            .iter()
+            .fold(Oklab::new(0.0, 0.0, 0.0), |sum, &p| sum + p)
+            / pixels.len() as f64;
+
+        let distance = |color: Oklab<f64>| {
+            let difference = color - dense;
+            (difference.l.powi(2) + difference.a.powi(2) + difference.b.powi(2)).sqrt()
+        };
+        assert!(distance(centroid) < 0.01);
+        assert!(distance(centroid) < distance(mean) / 4.0);
+
+        assert_eq!(delta_e_weighted_centroid::<Oklab<f64>, f64>(&[]), None);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_quantize_matches_serial() {
+        use super::par_quantize_with_error;
+
+        let palette: Vec<Srgb> = (0..16)
+            .map(|i| {
+                let i = i as f32;
+                Srgb::new(i / 15.0, (i * 7.0) % 16.0 / 15.0, (i * 11.0) % 16.0 / 15.0)
+            })
+            .collect();
+        let pixels: Vec<Srgb> = (0..10_000u32)
+            .map(|i| {
+                let value = |seed: u32| (i.wrapping_mul(seed) % 1000) as f32 / 999.0;
+                Srgb::new(value(7919), value(104_729), value(1_299_709))
+            })
+            .collect();
+
+        let serial = quantize_with_error(&pixels, &palette);
+        let parallel = par_quantize_with_error(&pixels, &palette);
+        assert_eq!(parallel, serial);
+        assert_eq!(par_quantize_with_error(&[], &palette), (vec![], 0.0));
+    }
+
+    #[test]
+    fn filter_by_contrast_removes_low_contrast() {
+        let background = Lab::<D65, f64>::new(95.0, 0.0, 0.0);
+        let candidates = [
+            Lab::new(20.0, 10.0, -30.0),
+            Lab::new(90.0, -5.0, 20.0),
+            Lab::new(45.0, 40.0, 30.0),
+            Lab::new(70.0, 0.0, 0.0),
+        ];
+
+        let kept = filter_by_contrast(&candidates, background, 4.5);
+        assert_eq!(kept, [candidates[0], candidates[2]]);
+        for &candidate in candidates.iter() {
+            let ratio = candidate.get_contrast_ratio(background);
+            assert_eq!(kept.contains(&candidate), ratio >= 4.5);
+        }
+
+        assert_eq!(filter_by_contrast(&candidates, background, 1.0).len(), 4);
+        assert!(filter_by_contrast(&candidates, background, 21.5).is_empty());
+    }
+
+    #[test]
+    fn robust_mean_rejects_outlier() {
+        let mut pixels: Vec<Srgb> = (0..20)
+            .map(|i| {
+                let gray = 0.45 + (i % 5) as f32 * 0.025;
+                Srgb::new(gray, gray, gray)
+            })
+            .collect();
+        pixels.push(Srgb::new(0.9, 0.05, 0.05));
+
+        let mean = robust_mean(&pixels, 2.0).unwrap();
+        assert_relative_eq!(mean.red, mean.green, epsilon = 0.001);
+        assert_relative_eq!(mean.green, mean.blue, epsilon = 0.001);
+        assert_relative_eq!(mean.red, 0.5, epsilon = 0.01);
+
+        let identical = vec![Srgb::new(0.2f32, 0.6, 0.4); 5];
+        assert_relative_eq!(
+            robust_mean(&identical, 2.0).unwrap(),
+            identical[0],
+            epsilon = 0.0001
+        );
+
+        assert_eq!(robust_mean::<Srgb, f32>(&[], 2.0), None);
+    }
+
+    #[test]
+    fn quantize_to_source_colors_has_no_error() {
+        let palette = [
+            Srgb::new(0.0f32, 0.0, 0.0),
+            Srgb::new(1.0, 0.5, 0.0),
+            Srgb::new(0.2, 0.4, 0.8),
+            Srgb::new(1.0, 1.0, 1.0),
+        ];
+        let pixels = [palette[2], palette[0], palette[3], palette[2], palette[1]];
+
+        let (indices, mean_error) = quantize_with_error(&pixels, &palette);
+        assert_eq!(indices, [2, 0, 3, 2, 1]);
+        assert_eq!(mean_error, 0.0);
+
+        let (indices, mean_error) = quantize_with_error(&[], &palette);
+        assert!(indices.is_empty());
+        assert_eq!(mean_error, 0.0);
+    }
+
+    #[test]
+    fn gray_and_vivid_colorfulness() {
+        let gray: Vec<Srgb> = (0..=10)
+            .map(|i| {
+                let value = i as f32 / 10.0;
+                Srgb::new(value, value, value)
+            })
+            .collect();
+        assert!(colorfulness_of_image(&gray) < 0.001);
+        assert!(gray.iter().all(|&color| colorfulness(color) < 0.001));
+
+        let vivid = [
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+            Srgb::new(0.0, 0.0, 1.0),
+            Srgb::new(1.0, 1.0, 0.0),
+        ];
+        assert!(colorfulness_of_image(&vivid) > 109.0);
+
+        let muted = [Srgb::new(0.6, 0.5, 0.5), Srgb::new(0.5, 0.5, 0.6)];
+        assert!(colorfulness_of_image(&muted) < colorfulness_of_image(&vivid));
+
+        assert_eq!(colorfulness_of_image::<Srgb>(&[]), 0.0);
+    }
+
+    #[test]
+    fn auto_contrast_stretches_low_contrast() {
+        let mut pixels: Vec<Srgb> = (0..1000)
+            .map(|i| {
+                let value = 0.4 + (i % 100) as f32 * 0.002;
+                Srgb::new(value, value * 0.9, value)
+            })
+            .collect();
+        // A few stray pixels that are clipped away.
+        pixels.push(Srgb::new(0.0, 0.0, 0.0));
+        pixels.push(Srgb::new(1.0, 1.0, 1.0));
+
+        let (black, white) = auto_contrast(&pixels, 1.0);
+        assert!(black > 0.35 && black < 0.45, "black: {}", black);
+        assert!(white > 0.5 && white < 0.65, "white: {}", white);
+
+        let luma = |pixel: &Srgb| 0.2126 * pixel.red + 0.7152 * pixel.green + 0.0722 * pixel.blue;
+        let range = |pixels: &[Srgb]| {
+            let lumas = pixels[..1000].iter().map(luma);
+            let min = lumas.clone().fold(f32::INFINITY, f32::min);
+            let max = lumas.fold(0.0, f32::max);
+            max - min
+        };
+
+        let before = range(&pixels);
+        apply_levels(&mut pixels, black, white);
+        let after = range(&pixels);
+        assert!(after > before * 4.0, "{} -> {}", before, after);
+        assert!(pixels.iter().all(|pixel| pixel.is_within_bounds()));
+
+        assert_eq!(auto_contrast(&[], 1.0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn diff_palettes() {
+        let a: Vec<Srgb<u8>> = (0..16u8).map(|i| Srgb::new(i * 16, 0, 255 - i)).collect();
+        assert!(palette_diff(&a, &a).is_empty());
+
+        let mut b = a.clone();
+        b[5] = Srgb::new(1, 2, 3);
+        let diff = palette_diff(&a, &b);
+        assert_eq!(diff, [(5, Srgb::new(1, 2, 3))]);
+
+        let mut animated = a.clone();
+        apply_palette_diff(&mut animated, &diff);
+        assert_eq!(animated, b);
+
+        b.push(Srgb::new(4, 5, 6));
+        assert_eq!(palette_diff(&a, &b).len(), 2);
+        assert!(palette_diff(&b[..4], &a[..4]).is_empty());
+    }
+}
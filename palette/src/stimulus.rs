@@ -108,6 +108,36 @@ impl<T> IntoStimulus<T> for T {
     }
 }
 
+/// The error type returned when a component type conversion would lose
+/// precision. See for example [`Rgb::try_into_format`](crate::rgb::Rgb::try_into_format).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LossyFormatError;
+
+impl core::fmt::Display for LossyFormatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the value can't be represented exactly in the new format")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LossyFormatError {}
+
+/// Convert `value` into `U`, unless it changes when it's converted back.
+#[inline]
+pub(crate) fn try_from_stimulus<T, U>(value: T) -> Result<U, LossyFormatError>
+where
+    T: FromStimulus<U> + PartialEq + Clone,
+    U: FromStimulus<T> + Clone,
+{
+    let converted = U::from_stimulus(value.clone());
+
+    if T::from_stimulus(converted.clone()) == value {
+        Ok(converted)
+    } else {
+        Err(LossyFormatError)
+    }
+}
+
 // C23 = 2^23, in f32
 // C52 = 2^52, in f64
 const C23: u32 = 0x4b00_0000;
@@ -265,7 +295,7 @@ convert_uint_to_uint!(u128; via f64 (u8, u16, u32, u64););
 
 #[cfg(test)]
 mod test {
-    use crate::stimulus::IntoStimulus;
+    use crate::stimulus::{try_from_stimulus, IntoStimulus, LossyFormatError};
     use approx::assert_relative_eq;
 
     #[test]
@@ -371,4 +401,27 @@ mod test {
             assert_relative_eq!(IntoStimulus::<f64>::into_stimulus(n), into_stimulus_old(n))
         }
     }
+
+    #[test]
+    fn u8_to_u16_replicates() {
+        for n in 0..=255u8 {
+            let wide: u16 = n.into_stimulus();
+            assert_eq!(wide, u16::from_be_bytes([n, n]));
+        }
+    }
+
+    #[test]
+    fn u16_to_u8_narrows() {
+        for n in 0..=255u8 {
+            let wide = u16::from_be_bytes([n, n]);
+            assert_eq!(IntoStimulus::<u8>::into_stimulus(wide), n);
+            assert_eq!(try_from_stimulus::<u16, u8>(wide), Ok(n));
+        }
+
+        assert_eq!(IntoStimulus::<u8>::into_stimulus(0x1234u16), 0x12);
+        assert_eq!(IntoStimulus::<u8>::into_stimulus(0x12A0u16), 0x13);
+        assert_eq!(try_from_stimulus::<u16, u8>(0x1234), Err(LossyFormatError));
+        assert_eq!(try_from_stimulus::<f32, u8>(0.5), Err(LossyFormatError));
+        assert_eq!(try_from_stimulus::<f32, u8>(1.0), Ok(255));
+    }
 }
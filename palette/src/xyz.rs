@@ -23,7 +23,7 @@ use crate::{
     rgb::{Rgb, RgbSpace, RgbStandard},
     white_point::{Any, WhitePoint, D65},
     Alpha, Clamp, ClampAssign, ComponentWise, IsWithinBounds, Lab, Lighten, LightenAssign, Luma,
-    Luv, Mix, MixAssign, Oklab, Oklch, RelativeContrast, Yxy,
+    Luv, Mat3, Mix, MixAssign, Oklab, Oklch, RelativeContrast, Yxy,
 };
 
 /// CIE 1931 XYZ with an alpha component. See the [`Xyza` implementation in
@@ -157,6 +157,50 @@ where
     }
 }
 
+impl<Wp, T> Xyz<Wp, T>
+where
+    T: Zero + One + IsValidDivisor + Arithmetics + Clone,
+{
+    /// Create an XYZ color from the CIE chromaticity coordinates `x` and `y`,
+    /// and the luminance `Y`.
+    ///
+    /// This is the same as converting from [`Yxy`], and is useful when the
+    /// values come from a light measurement. The `X` and `Z` values are `0`
+    /// if `y` is `0`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{white_point::D65, Xyz};
+    ///
+    /// let white = Xyz::<D65, f64>::from_chromaticity(0.3127, 0.3290, 1.0);
+    /// assert_relative_eq!(white, Xyz::new(0.950456, 1.0, 1.089058), epsilon = 0.000001);
+    /// ```
+    pub fn from_chromaticity(x: T, y: T, luminance: T) -> Self {
+        Self::from_color_unclamped(Yxy::new(x, y, luminance))
+    }
+}
+
+impl<Wp, T> Xyz<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real + Arithmetics + PartialOrd + Clone,
+{
+    /// Add two colors as lights shining on the same spot. The sum is scaled
+    /// down to the luminance of the white point if it's brighter, while
+    /// keeping its chromaticity.
+    pub(crate) fn add_lights(self, other: Self) -> Self {
+        let sum = self + other;
+
+        let max_y = Wp::get_xyz().y;
+        if sum.y > max_y {
+            let scale = max_y / &sum.y;
+            sum * scale
+        } else {
+            sum
+        }
+    }
+}
+
 ///<span id="Xyza"></span>[`Xyza`](crate::Xyza) implementations.
 impl<Wp, T, A> Alpha<Xyz<Wp, T>, A> {
     /// Create a CIE XYZ color with transparency.
@@ -458,6 +502,70 @@ impl_color_sub!(Xyz<Wp, T>, [x, y, z], white_point);
 impl_color_mul!(Xyz<Wp, T>, [x, y, z], white_point);
 impl_color_div!(Xyz<Wp, T>, [x, y, z], white_point);
 
+/// Multiply the color with a 3x3 matrix, treating it as a column vector, as in
+/// `M × c`.
+///
+/// ```
+/// use palette::{white_point::D65, Mat3, Xyz};
+///
+/// let swap_x_z: Mat3<f32> = [
+///     0.0, 0.0, 1.0,
+///     0.0, 1.0, 0.0,
+///     1.0, 0.0, 0.0,
+/// ];
+///
+/// let color = Xyz::<D65, f32>::new(0.1, 0.2, 0.3);
+/// assert_eq!(swap_x_z * color, Xyz::new(0.3, 0.2, 0.1));
+/// ```
+impl<Wp, T> Mul<Xyz<Wp, T>> for Mat3<T>
+where
+    T: Arithmetics,
+{
+    type Output = Xyz<Wp, T>;
+
+    #[inline]
+    fn mul(self, color: Xyz<Wp, T>) -> Self::Output {
+        multiply_xyz(self, color.with_white_point()).with_white_point()
+    }
+}
+
+/// Multiply the color with a 3x3 matrix, treating it as a row vector, as in
+/// `c × M`.
+///
+/// This is the same as multiplying the transpose of the matrix with the color
+/// as a column vector.
+///
+/// ```
+/// use palette::{white_point::D65, Mat3, Xyz};
+///
+/// let matrix: Mat3<f32> = [
+///     1.0, 1.0, 0.0,
+///     0.0, 1.0, 0.0,
+///     0.0, 0.0, 1.0,
+/// ];
+///
+/// let color = Xyz::<D65, f32>::new(0.1, 0.2, 0.3);
+/// assert_eq!(color * matrix, Xyz::new(0.1, 0.3, 0.3));
+/// assert_eq!(matrix * color, Xyz::new(0.3, 0.2, 0.3));
+/// ```
+impl<Wp, T> Mul<Mat3<T>> for Xyz<Wp, T>
+where
+    T: Arithmetics,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, matrix: Mat3<T>) -> Self::Output {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = matrix;
+
+        multiply_xyz(
+            [m0, m3, m6, m1, m4, m7, m2, m5, m8],
+            self.with_white_point(),
+        )
+        .with_white_point()
+    }
+}
+
 impl_array_casts!(Xyz<Wp, T>, [T; 3]);
 
 impl_eq!(Xyz<Wp>, [x, y, z]);
@@ -570,7 +678,7 @@ unsafe impl<Wp: 'static, T> bytemuck::Pod for Xyz<Wp, T> where T: bytemuck::Pod
 mod test {
     use super::Xyz;
     use crate::white_point::D65;
-    use crate::{FromColor, LinLuma, LinSrgb};
+    use crate::{FromColor, Lab, LinLuma, LinSrgb, Luv, Mix};
 
     #[cfg(feature = "random")]
     use crate::white_point::WhitePoint;
@@ -579,6 +687,48 @@ mod test {
     const Y_N: f64 = 1.0;
     const Z_N: f64 = 1.08883;
 
+    #[test]
+    fn add_lights() {
+        let red = Xyz::<D65, f64>::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+        let green = Xyz::<D65, f64>::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+        let yellow = LinSrgb::new(1.0, 1.0, 0.0);
+
+        let additive = LinSrgb::from_color(red.add_lights(green));
+        assert_relative_eq!(additive, yellow, epsilon = 0.0001);
+
+        // The sum is not brighter than white.
+        let white = Xyz::<D65, f64>::from_color(LinSrgb::new(1.0, 1.0, 1.0));
+        let bright = LinSrgb::from_color(white.add_lights(white));
+        assert_relative_eq!(bright, LinSrgb::new(1.0, 1.0, 1.0), epsilon = 0.0001);
+
+        // Lab and Luv mix additively through XYZ, unlike their perceptual
+        // mixing, which gives a darker color in between.
+        let (red_lab, green_lab) = (Lab::from_color(red), Lab::from_color(green));
+        let additive = LinSrgb::from_color(red_lab.mix_additive(green_lab));
+        assert_relative_eq!(additive, yellow, epsilon = 0.0001);
+        let perceptual = LinSrgb::from_color(red_lab.mix(green_lab, 0.5));
+        assert!(perceptual.red < 0.9 && perceptual.green < 0.9);
+
+        let (red_luv, green_luv) = (Luv::from_color(red), Luv::from_color(green));
+        let additive = LinSrgb::from_color(red_luv.mix_additive(green_luv));
+        assert_relative_eq!(additive, yellow, epsilon = 0.0001);
+        let perceptual = LinSrgb::from_color(red_luv.mix(green_luv, 0.5));
+        assert!(perceptual.red < 0.9 && perceptual.green < 0.9);
+    }
+
+    #[test]
+    fn from_chromaticity() {
+        let color = Xyz::<D65, f64>::from_chromaticity(0.64, 0.33, 0.2126);
+        assert_relative_eq!(
+            color,
+            Xyz::new(0.412315, 0.2126, 0.019327),
+            epsilon = 0.000001
+        );
+
+        let black = Xyz::<D65, f64>::from_chromaticity(0.0, 0.0, 0.0);
+        assert_relative_eq!(black, Xyz::new(0.0, 0.0, 0.0));
+    }
+
     #[test]
     fn luma() {
         let a = Xyz::<D65>::from_color(LinLuma::new(0.5));
@@ -607,6 +757,15 @@ mod test {
         assert_relative_eq!(a, b, epsilon = 0.0001);
     }
 
+    #[test]
+    fn identity_matrix_multiplication() {
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let color = Xyz::<D65, f64>::new(0.3, 0.7, 0.9);
+
+        assert_eq!(identity * color, color);
+        assert_eq!(color * identity, color);
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
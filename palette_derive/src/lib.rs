@@ -34,6 +34,7 @@ macro_rules! syn_try {
 mod alpha;
 mod cast;
 mod convert;
+mod literal;
 mod meta;
 mod util;
 
@@ -72,3 +73,8 @@ pub fn derive_from_color_unclamped(tokens: TokenStream) -> TokenStream {
 pub fn derive_array_cast(tokens: TokenStream) -> TokenStream {
     syn_try!(cast::derive_array_cast(tokens))
 }
+
+#[proc_macro]
+pub fn color(tokens: TokenStream) -> TokenStream {
+    syn_try!(literal::expand_color(tokens))
+}
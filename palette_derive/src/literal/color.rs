@@ -0,0 +1,190 @@
+use proc_macro::TokenStream;
+
+use quote::quote;
+use syn::LitStr;
+
+use crate::util;
+
+pub fn expand(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::Error>> {
+    let literal: LitStr = syn::parse(tokens).map_err(|error| vec![error])?;
+    let components = parse_color(&literal.value())
+        .map_err(|message| vec![syn::Error::new(literal.span(), message)])?;
+
+    let rgb_path = util::path(["rgb", "Rgb"], false);
+    let srgb_path = util::path(["encoding", "Srgb"], false);
+    let alpha_path = util::path(["Alpha"], false);
+
+    let tokens = match components {
+        [red, green, blue, None] => quote! {
+            #rgb_path::<#srgb_path, u8>::new(#red, #green, #blue)
+        },
+        [red, green, blue, Some(alpha)] => quote! {
+            #alpha_path::<#rgb_path<#srgb_path, u8>, u8>::new(#red, #green, #blue, #alpha)
+        },
+    };
+
+    Ok(tokens.into())
+}
+
+/// Parses a hex code or an `rgb(...)`/`rgba(...)` function into its
+/// components. The alpha component is `None` if it's not part of the input.
+fn parse_color(input: &str) -> Result<[Option<u8>; 4], String> {
+    let input = input.trim();
+
+    if let Some(hex) = input.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    let function = input
+        .strip_prefix("rgba(")
+        .map(|arguments| ("rgba", arguments, 4))
+        .or_else(|| {
+            input
+                .strip_prefix("rgb(")
+                .map(|arguments| ("rgb", arguments, 3))
+        });
+
+    if let Some((name, arguments, expected_components)) = function {
+        let arguments = arguments
+            .strip_suffix(')')
+            .ok_or_else(|| format!("expected `)` at the end of `{}`", input))?;
+        return parse_function(name, arguments, expected_components);
+    }
+
+    Err(format!(
+        "`{}` is not a valid color, expected a hex code, such as `#ff8000`, or `rgb(255, 128, 0)`",
+        input
+    ))
+}
+
+fn parse_hex(hex: &str) -> Result<[Option<u8>; 4], String> {
+    let digits = hex
+        .chars()
+        .map(|digit| {
+            digit
+                .to_digit(16)
+                .map(|value| value as u8)
+                .ok_or_else(|| format!("`{}` is not a hexadecimal digit", digit))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let components: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => {
+            return Err(format!(
+                "`#{}` has {} digits, but hex codes should have 3, 4, 6 or 8 digits",
+                hex,
+                digits.len()
+            ))
+        }
+    };
+
+    Ok([
+        Some(components[0]),
+        Some(components[1]),
+        Some(components[2]),
+        components.get(3).copied(),
+    ])
+}
+
+/// Parses the comma separated components of `rgb(...)` or `rgba(...)`, where
+/// `rgb` must have 3 components and `rgba` must have 4.
+fn parse_function(
+    name: &str,
+    arguments: &str,
+    expected_components: usize,
+) -> Result<[Option<u8>; 4], String> {
+    let components = arguments
+        .split(',')
+        .map(|argument| {
+            let argument = argument.trim();
+            argument
+                .parse::<u8>()
+                .map_err(|_| format!("`{}` is not a number between 0 and 255", argument))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if components.len() != expected_components {
+        return Err(format!(
+            "`{}` expects {} components, but found {}",
+            name,
+            expected_components,
+            components.len()
+        ));
+    }
+
+    Ok([
+        Some(components[0]),
+        Some(components[1]),
+        Some(components[2]),
+        components.get(3).copied(),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_color;
+
+    #[test]
+    fn hex_codes() {
+        assert_eq!(
+            parse_color("#f80"),
+            Ok([Some(255), Some(136), Some(0), None])
+        );
+        assert_eq!(
+            parse_color("#f808"),
+            Ok([Some(255), Some(136), Some(0), Some(136)])
+        );
+        assert_eq!(
+            parse_color("#ff8000"),
+            Ok([Some(255), Some(128), Some(0), None])
+        );
+        assert_eq!(
+            parse_color(" #FF800080 "),
+            Ok([Some(255), Some(128), Some(0), Some(128)])
+        );
+    }
+
+    #[test]
+    fn invalid_hex_codes() {
+        for input in &["#", "#f", "#ff", "#ff800", "#ff80000", "#ff8000800"] {
+            assert!(parse_color(input).is_err(), "{} should fail", input);
+        }
+
+        assert!(parse_color("#ff80zz").is_err());
+        assert!(parse_color("ff8000").is_err());
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(
+            parse_color("rgb(255, 128, 0)"),
+            Ok([Some(255), Some(128), Some(0), None])
+        );
+        assert_eq!(
+            parse_color("rgba(255,128,0,64)"),
+            Ok([Some(255), Some(128), Some(0), Some(64)])
+        );
+    }
+
+    #[test]
+    fn invalid_functions() {
+        assert!(parse_color("rgb(256, 0, 0)").is_err());
+        assert!(parse_color("rgb(-1, 0, 0)").is_err());
+        assert!(parse_color("rgb(red, 0, 0)").is_err());
+        assert!(parse_color("rgb(0.5, 0, 0)").is_err());
+        assert!(parse_color("rgb(1, 2, 3").is_err());
+    }
+
+    #[test]
+    fn function_component_count() {
+        assert!(parse_color("rgb(1, 2, 3, 4)").is_err());
+        assert!(parse_color("rgba(1, 2, 3)").is_err());
+        assert!(parse_color("rgb(1, 2)").is_err());
+        assert!(parse_color("rgba(1, 2, 3, 4, 5)").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::color::expand as expand_color;
+
+mod color;